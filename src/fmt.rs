@@ -0,0 +1,161 @@
+//! Types to support formatting of [quantities](http://jcgm.bipm.org/vim/en/1.1.html) in a given
+//! [measurement unit](http://jcgm.bipm.org/vim/en/1.9.html).
+//!
+//! Each quantity has an `into_format_args` method that combines the quantity with a measurement
+//! unit and a [`DisplayStyle`](enum.DisplayStyle.html) into a struct implementing `Display`. The
+//! `format_args` function creates reusable arguments that can be combined with multiple quantities.
+//! Formatting flags (width, precision, sign, ...) are applied to the value which is followed by the
+//! unit separated by a space. `format_args` and `into_format_args` are available in `no_std`
+//! environments.
+//!
+#![cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust")]
+#![cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+//! # extern crate uom;
+//! # use uom::si::f32::*;
+//! # use uom::si::length::{centimeter, kilometer, meter};
+//! # use uom::fmt::DisplayStyle::*;
+//! # fn main() {
+//! let l1 = Length::new::<meter>(1234.5);
+//! let l2 = Length::new::<meter>(1.0);
+//! let a = Length::format_args(centimeter, Description);
+//!
+//! assert_eq!("1.23 km", format!("{:.2}", l1.into_format_args(kilometer, Abbreviation)));
+//! assert_eq!("+1234.5 meters", format!("{:+}", l1.into_format_args(meter, Description)));
+//! assert_eq!("123450 centimeters", format!("{}", a.with(l1)));
+//! assert_eq!("100 centimeters", format!("{}", a.with(l2)));
+//! # }
+//! ```
+//!
+//! The `into_auto_format_args` method of each quantity selects the measurement unit automatically
+//! from the units declared for the quantity so that the formatted value falls within `[1, 1000)`
+//! when possible. [`UnitSelection`](enum.UnitSelection.html) controls which units are considered.
+//!
+#![cfg_attr(all(feature = "si", feature = "f64"), doc = " ```rust")]
+#![cfg_attr(not(all(feature = "si", feature = "f64")), doc = " ```rust,ignore")]
+//! # extern crate uom;
+//! # use uom::si::f64::*;
+//! # use uom::si::length::meter;
+//! # use uom::si::time::second;
+//! # use uom::fmt::DisplayStyle::*;
+//! # use uom::fmt::UnitSelection::*;
+//! # fn main() {
+//! let l = Length::new::<meter>(1234.5);
+//! let t = Time::new::<second>(7_200.0);
+//!
+//! assert_eq!("1.23 km", format!("{:.2}", l.into_auto_format_args(Abbreviation, Prefixed)));
+//! assert_eq!("3.2 µs", format!("{}", Time::new::<second>(3.2E-6)
+//!     .into_auto_format_args(Abbreviation, Prefixed)));
+//! assert_eq!("7.2 ks", format!("{}", t.into_auto_format_args(Abbreviation, Prefixed)));
+//! assert_eq!("2 hours", format!("{}", t.into_auto_format_args(Description, All)));
+//! # }
+//! ```
+//!
+//! The `into_mixed` method of each quantity decomposes the quantity into mixed units given as a
+//! tuple of measurement units ordered from the largest to the smallest. The result displays the
+//! integer value in each unit except the smallest followed by the remainder in the smallest unit.
+//! Formatting flags are applied to the remainder and rounding carries into the larger units. The
+//! sign of a negative quantity is displayed once. `from_mixed` is the inverse constructor.
+//!
+#![cfg_attr(all(feature = "si", feature = "f64"), doc = " ```rust")]
+#![cfg_attr(not(all(feature = "si", feature = "f64")), doc = " ```rust,ignore")]
+//! # extern crate uom;
+//! # use uom::si::f64::*;
+//! # use uom::si::length::{foot, inch, meter};
+//! # use uom::si::mass::{ounce, pound};
+//! # use uom::si::time::{hour, minute, second};
+//! # fn main() {
+//! let l = Length::new::<inch>(67.5);
+//! let m = l.into_mixed((foot, inch));
+//!
+//! assert_eq!("5 ft 7.5 in", format!("{:.1}", m));
+//! assert!((Length::from_mixed((foot, inch), m.values) - l).abs() < Length::new::<meter>(1.0E-9));
+//! assert_eq!("2 h 13 min 5 s",
+//!     format!("{:.0}", Time::new::<second>(7_985.0).into_mixed((hour, minute, second))));
+//! assert_eq!("3 lb 4 oz", format!("{:.0}", Mass::new::<ounce>(52.0).into_mixed((pound, ounce))));
+//! assert_eq!("6 ft 0 in", format!("{:.0}", Length::new::<inch>(71.9).into_mixed((foot, inch))));
+//! assert_eq!("-1 h 30 min 0 s",
+//!     format!("{:.0}", Time::new::<minute>(-90.0).into_mixed((hour, minute, second))));
+//! # }
+//! ```
+
+use UnitDescriptor;
+
+/// Display style for formatting a quantity's measurement unit.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DisplayStyle {
+    /// Display the value followed by the unit abbreviation (e.g. `1 m`, `2 m`).
+    Abbreviation,
+    /// Display the value followed by the unit singular or plural description (e.g. `1 meter`,
+    /// `2 meters`).
+    Description,
+}
+
+/// Measurement units considered when the unit used to format a quantity is selected automatically.
+/// Units with a conversion constant (e.g. `degree_celsius`) are never selected automatically.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum UnitSelection {
+    /// Consider the unit with a conversion factor of one and units with an SI prefix that is a
+    /// power of 1000 (e.g. `kilometer`, `microsecond`). Determined by comparing the conversion
+    /// factor of the unit with the values of the `prefix!` macro.
+    Prefixed,
+    /// Consider all units declared for the quantity, including customary units (e.g. `hour`,
+    /// `day`, `mile`).
+    All,
+}
+
+/// Conversion factors of the SI prefixes that are powers of 1000, `prefix!(yocto)` through
+/// `prefix!(yotta)`, along with `prefix!(none)`.
+const PREFIXES: [f64; 17] = [
+    1.0E-24, 1.0E-21, 1.0E-18, 1.0E-15, 1.0E-12, 1.0E-9, 1.0E-6, 1.0E-3, 1.0E0,
+    1.0E3, 1.0E6, 1.0E9, 1.0E12, 1.0E15, 1.0E18, 1.0E21, 1.0E24,
+];
+
+/// Selects the unit to format the given value in base units. The unit with the largest conversion
+/// factor not greater than the magnitude of the value is selected. The unit with the smallest
+/// conversion factor is selected when the magnitude is smaller than all conversion factors and the
+/// unit with a conversion factor of one is preferred for zero and non-finite values. Returns
+/// `None` when none of the units can be selected.
+#[doc(hidden)]
+pub fn select_unit(
+    units: &'static [UnitDescriptor],
+    value: f64,
+    selection: UnitSelection,
+) -> Option<&'static UnitDescriptor> {
+    // Conversion factors calculated from prefixes (e.g. `prefix!(micro) / prefix!(kilo)`) may
+    // differ from the prefix in the last place.
+    let is_prefixed = |factor: f64| {
+        PREFIXES.iter().any(|p| {
+            let r = factor / p - 1.0;
+
+            -1.0E-9 < r && r < 1.0E-9
+        })
+    };
+    let mut candidates = units.iter().filter(|u| {
+        0.0 == u.constant() && 0.0 < u.conversion()
+            && (selection == UnitSelection::All || is_prefixed(u.conversion()))
+    });
+    let magnitude = if value < 0.0 { -value } else { value };
+    let mut selected = match candidates.next() {
+        Some(unit) => unit,
+        None => return None,
+    };
+
+    if 0.0 == magnitude || !magnitude.is_finite() {
+        return Some(units.iter()
+            .find(|u| 1.0 == u.conversion() && 0.0 == u.constant())
+            .unwrap_or(selected));
+    }
+
+    for unit in candidates {
+        let fits = unit.conversion() <= magnitude;
+        let selected_fits = selected.conversion() <= magnitude;
+
+        if (fits && (!selected_fits || unit.conversion() > selected.conversion()))
+            || (!fits && !selected_fits && unit.conversion() < selected.conversion())
+        {
+            selected = unit;
+        }
+    }
+
+    Some(selected)
+}
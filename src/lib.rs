@@ -69,6 +69,10 @@
 //!         "rational", "rational32", "rational64", "bigrational", # Integer ratio storage types.
 //!         "f32", "f64", # Floating point storage types.
 //!         "si", "std", # Built-in SI system and std library support.
+//!         "serde", # Serde support.
+//!         "try_from", # `TryFrom` support.
+//!         "approx", # Approximate equality support.
+//!         "testing", # Testing utilities.
 //!     ]
 //! }
 //! ```
@@ -81,8 +85,24 @@
 //!    default.
 //!  * `std` -- Feature to compile with standard library support. Disabling this feature compiles
 //!    `uom` with `no_std`. Enabled by default.
+//!  * `serde` -- Feature to enable support for serialization and deserialization of quantities
+//!    with the [Serde][serde] crate. Disabled by default.
+//!  * `try_from` -- Feature to implement `TryFrom` to convert a runtime-dimensioned `DynQuantity`
+//!    into a `Quantity` and fallible conversions between `si::Time` and `core::time::Duration`.
+//!    Requires `rustc` 1.34.0 or later. Disabled by default.
+//!  * `approx` -- Feature to implement `AbsDiffEq`, `RelativeEq`, and `UlpsEq` for quantities
+//!    with the [approx][approx] crate. `max_relative` is given as a quantity of the same dimension
+//!    whose value in base units is used as the ratio. Disabled by default.
+//!  * `testing` -- Feature to enable the [`testing`](testing/index.html) module with
+//!    [quickcheck][quickcheck] `Arbitrary` implementations and approximate equality assertions for
+//!    quantities. Enables `approx` and `std`. Disabled by default. The `quickcheck` feature only
+//!    exists because `quickcheck` is an optional dependency of `testing` and has no effect on its
+//!    own.
 //!
 //! [si]: http://jcgm.bipm.org/vim/en/1.16.html
+//! [serde]: https://serde.rs/
+//! [approx]: https://crates.io/crates/approx
+//! [quickcheck]: https://crates.io/crates/quickcheck
 //!
 //! ## Design
 //! Rather than working with [measurement units](http://jcgm.bipm.org/vim/en/1.9.html) (meter,
@@ -159,12 +179,26 @@ pub extern crate num;
 #[doc(hidden)]
 pub extern crate typenum;
 
-#[cfg(all(test, any(feature = "f32", feature = "f64")))]
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub extern crate serde;
+
+#[cfg(feature = "approx")]
+#[cfg_attr(all(test, any(feature = "f32", feature = "f64")), macro_use)]
+#[doc(hidden)]
+pub extern crate approx;
+#[cfg(all(test, not(feature = "approx"), any(feature = "f32", feature = "f64")))]
 #[macro_use]
 extern crate approx;
-#[cfg(test)]
+#[cfg(feature = "testing")]
+#[cfg_attr(test, macro_use)]
+#[doc(hidden)]
+pub extern crate quickcheck;
+#[cfg(all(test, not(feature = "testing")))]
 #[macro_use]
 extern crate quickcheck;
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
 #[cfg(test)]
 #[macro_use]
 extern crate static_assertions;
@@ -199,6 +233,16 @@ mod system;
 #[macro_use]
 mod quantity;
 
+pub mod fmt;
+pub mod str;
+
+#[cfg(feature = "serde")]
+pub mod serde_support;
+
+#[cfg(feature = "testing")]
+#[macro_use]
+pub mod testing;
+
 #[cfg(feature = "si")]
 #[macro_use]
 pub mod si;
@@ -206,6 +250,75 @@ pub mod si;
 #[cfg(test)]
 mod tests;
 
+/// Default [kind][kind] of quantities to allow addition, subtraction, multiplication, division,
+/// remainder, and negation. Quantities of a different kind (see the `kind` parameter of
+/// [`quantity!`](macro.quantity.html)) can't be mixed with quantities of the default kind even when
+/// their dimensions are the same and only support the operations included in the kind's marker
+/// traits.
+///
+/// [kind]: https://jcgm.bipm.org/vim/en/1.2.html
+pub trait Kind
+    : marker::Add
+    + marker::AddAssign
+    + marker::Sub
+    + marker::SubAssign
+    + marker::Mul
+    + marker::MulAssign
+    + marker::Div
+    + marker::DivAssign
+    + marker::Rem
+    + marker::RemAssign
+    + marker::Neg
+{
+}
+
+/// [Kind][kind] of plane angles. Angles support the same operations as the default
+/// [`Kind`](trait.Kind.html) but can't be mixed with quantities of other kinds that are also
+/// dimensionless (e.g. ratios). The trigonometric functions generated by the
+/// [`system!`](macro.system.html) macro are implemented for dimensionless quantities of this kind.
+///
+/// [kind]: https://jcgm.bipm.org/vim/en/1.2.html
+pub trait AngleKind: Kind {}
+
+/// Marker traits for the operations supported by a [kind][kind] of quantity. See
+/// [`Kind`](../trait.Kind.html).
+///
+/// [kind]: https://jcgm.bipm.org/vim/en/1.2.html
+pub mod marker {
+    /// `Add` operator for quantities.
+    pub trait Add {}
+
+    /// `AddAssign` operator for quantities.
+    pub trait AddAssign {}
+
+    /// `Sub` operator for quantities.
+    pub trait Sub {}
+
+    /// `SubAssign` operator for quantities.
+    pub trait SubAssign {}
+
+    /// `Mul` operator for quantities.
+    pub trait Mul {}
+
+    /// `MulAssign` operator for quantities.
+    pub trait MulAssign {}
+
+    /// `Div` operator for quantities.
+    pub trait Div {}
+
+    /// `DivAssign` operator for quantities.
+    pub trait DivAssign {}
+
+    /// `Rem` operator for quantities.
+    pub trait Rem {}
+
+    /// `RemAssign` operator for quantities.
+    pub trait RemAssign {}
+
+    /// `Neg` operator for quantities.
+    pub trait Neg {}
+}
+
 /// Trait to identify [units][units] which have a [conversion factor][factor].
 ///
 /// [units]: http://jcgm.bipm.org/vim/en/1.13.html
@@ -224,6 +337,46 @@ pub trait Conversion<V> {
         <Self::T as num::One>::one()
     }
 
+    /// Static [conversion constant][constant] for the given unit to the base unit for the
+    /// quantity. The constant is added to a value in the unit before the conversion factor is
+    /// applied when converting to the base unit (e.g. `273.15` for degree Celsius to kelvin) and
+    /// subtracted after the conversion factor is applied when converting from the base unit.
+    ///
+    /// Default implementation returns `Self::T::zero()`. Units without a constant defined by
+    /// [`quantity!`](macro.quantity.html) return `-0.0` for floating point storage types and
+    /// `ConstantOp::Add` so that the addition can be optimized away.
+    ///
+    /// [constant]: https://jcgm.bipm.org/vim/en/1.24.html
+    #[inline(always)]
+    #[allow(unused_variables)]
+    fn constant(op: ConstantOp) -> Self::T {
+        <Self::T as num::Zero>::zero()
+    }
+
+    /// Static [conversion factor][factor] for the given unit to the base unit for the quantity.
+    /// Returns `None` when the conversion factor type can't represent the conversion factor (e.g.
+    /// the conversion factor of `yottameter` for `i8`). `Self::conversion()` panics in this case.
+    ///
+    /// Default implementation returns `Some(Self::conversion())`.
+    ///
+    /// [factor]: https://jcgm.bipm.org/vim/en/1.24.html
+    #[inline(always)]
+    fn try_conversion() -> Option<Self::T> {
+        Some(Self::conversion())
+    }
+
+    /// Static [conversion constant][constant] for the given unit to the base unit for the
+    /// quantity. Returns `None` when the conversion factor type can't represent the conversion
+    /// constant. `Self::constant()` panics in this case.
+    ///
+    /// Default implementation returns `Some(Self::constant(op))`.
+    ///
+    /// [constant]: https://jcgm.bipm.org/vim/en/1.24.html
+    #[inline(always)]
+    fn try_constant(op: ConstantOp) -> Option<Self::T> {
+        Some(Self::constant(op))
+    }
+
     /// Instance [conversion factor][factor].
     ///
     /// Default implementation returns the static conversion `Self::conversion()`.
@@ -238,12 +391,24 @@ pub trait Conversion<V> {
     }
 }
 
+/// Operation in which a [conversion constant](trait.Conversion.html#method.constant) is used.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ConstantOp {
+    /// The constant is added when converting a value to the base unit.
+    Add,
+    /// The constant is subtracted when converting a value from the base unit.
+    Sub,
+}
+
 /// Trait representing a [conversion factor][factor].
 ///
 /// [factor]: https://jcgm.bipm.org/vim/en/1.24.html
 pub trait ConversionFactor<V>
-    : lib::ops::Div<Self, Output = Self>
+    : lib::ops::Add<Self, Output = Self>
+    + lib::ops::Sub<Self, Output = Self>
+    + lib::ops::Div<Self, Output = Self>
     + lib::ops::Mul<Self, Output = Self>
+    + num::Zero
     + num::One
 {
     /// Raises a `ConversionFactor<V>` to an integer power.
@@ -251,6 +416,144 @@ pub trait ConversionFactor<V>
 
     /// Converts a `ConversionFactor<V>` into its underlying type.
     fn value(self) -> V;
+
+    /// Converts a `ConversionFactor<V>` into its underlying type using the given rounding mode
+    /// when the underlying type can't represent the conversion factor exactly.
+    ///
+    /// Default implementation returns `self.value()`.
+    #[inline(always)]
+    #[allow(unused_variables)]
+    fn value_rounded(self, mode: RoundingMode) -> V
+    where
+        Self: Sized,
+    {
+        self.value()
+    }
+
+    /// Converts a `ConversionFactor<V>` into its underlying type. Returns
+    /// `Err(ConversionError::Inexact)` when the underlying type can't represent the conversion
+    /// factor exactly.
+    ///
+    /// Default implementation returns `Ok(self.value())`.
+    #[inline(always)]
+    fn try_value(self) -> Result<V, ConversionError>
+    where
+        Self: Sized,
+    {
+        Ok(self.value())
+    }
+}
+
+/// Rounding mode used when a value converted to a different measurement unit can't be represented
+/// exactly by an integer underlying storage type. Floating point and integer ratio storage types
+/// represent the converted value without rounding.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RoundingMode {
+    /// Round towards zero.
+    Truncate,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round to the nearest integer. Half-way cases are rounded to the nearest even integer.
+    HalfEven,
+}
+
+/// Error returned when a value can't be converted to a different measurement unit without loss.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ConversionError {
+    /// The converted value can't be represented exactly by the underlying storage type.
+    Inexact,
+    /// The conversion overflows the underlying storage type.
+    Overflow,
+}
+
+impl lib::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut lib::fmt::Formatter) -> lib::fmt::Result {
+        match *self {
+            ConversionError::Inexact => write!(f, "inexact conversion"),
+            ConversionError::Overflow => write!(f, "conversion overflow"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl lib::error::Error for ConversionError {
+    fn description(&self) -> &str {
+        match *self {
+            ConversionError::Inexact => "inexact conversion",
+            ConversionError::Overflow => "conversion overflow",
+        }
+    }
+}
+
+/// Runtime description of a [measurement unit][unit]. Returned by the `units` function of each
+/// quantity module (e.g. `uom::si::length::units()`).
+///
+/// [unit]: http://jcgm.bipm.org/vim/en/1.9.html
+#[derive(Clone, Copy, Debug)]
+pub struct UnitDescriptor {
+    /// Unit abbreviation.
+    pub abbreviation: &'static str,
+
+    /// Unit singular description.
+    pub singular: &'static str,
+
+    /// Unit plural description.
+    pub plural: &'static str,
+
+    /// Function returning the conversion factor. Use `conversion()` instead.
+    #[doc(hidden)]
+    pub conversion: fn() -> f64,
+
+    /// Function returning the conversion constant. Use `constant()` instead.
+    #[doc(hidden)]
+    pub constant: fn() -> f64,
+}
+
+impl UnitDescriptor {
+    /// Conversion factor to the base unit of the quantity. See
+    /// [`Conversion::conversion`](trait.Conversion.html#method.conversion).
+    #[inline(always)]
+    pub fn conversion(&self) -> f64 {
+        (self.conversion)()
+    }
+
+    /// Conversion constant added to a value in the unit before applying the conversion factor.
+    /// Zero for units without a constant. See
+    /// [`Conversion::constant`](trait.Conversion.html#method.constant).
+    #[inline(always)]
+    pub fn constant(&self) -> f64 {
+        (self.constant)()
+    }
+}
+
+impl PartialEq for UnitDescriptor {
+    fn eq(&self, other: &Self) -> bool {
+        self.abbreviation == other.abbreviation
+            && self.singular == other.singular
+            && self.plural == other.plural
+            && self.conversion() == other.conversion()
+            && self.constant() == other.constant()
+    }
+}
+
+/// Error returned when the dimension of a runtime-dimensioned quantity doesn't match the dimension
+/// required by an operation or conversion, or when an exponent of the dimension overflows.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DimensionError;
+
+impl lib::fmt::Display for DimensionError {
+    fn fmt(&self, f: &mut lib::fmt::Formatter) -> lib::fmt::Result {
+        write!(f, "mismatched dimensions")
+    }
+}
+
+#[cfg(feature = "std")]
+impl lib::error::Error for DimensionError {
+    fn description(&self) -> &str {
+        "mismatched dimensions"
+    }
 }
 
 storage_types! {
@@ -300,6 +603,40 @@ storage_types! {
         fn value(self) -> V {
             self.to_integer()
         }
+
+        #[inline(always)]
+        fn value_rounded(self, mode: ::RoundingMode) -> V {
+            use ::num::Integer;
+
+            match mode {
+                ::RoundingMode::Truncate => self.to_integer(),
+                ::RoundingMode::Floor => self.floor().to_integer(),
+                ::RoundingMode::Ceil => self.ceil().to_integer(),
+                ::RoundingMode::HalfEven => {
+                    let two = <V as ::num::One>::one() + <V as ::num::One>::one();
+
+                    let floor = self.floor().to_integer();
+
+                    // Half-way cases are the only reduced ratios with a denominator of two.
+                    if *self.denom() != two {
+                        self.round().to_integer()
+                    } else if floor.is_even() {
+                        floor
+                    } else {
+                        self.ceil().to_integer()
+                    }
+                }
+            }
+        }
+
+        #[inline(always)]
+        fn try_value(self) -> Result<V, ::ConversionError> {
+            if self.is_integer() {
+                Ok(self.to_integer())
+            } else {
+                Err(::ConversionError::Inexact)
+            }
+        }
     }
 }
 
@@ -329,6 +666,40 @@ storage_types! {
         fn value(self) -> V {
             self.to_integer()
         }
+
+        #[inline(always)]
+        fn value_rounded(self, mode: ::RoundingMode) -> V {
+            use ::num::Integer;
+
+            match mode {
+                ::RoundingMode::Truncate => self.to_integer(),
+                ::RoundingMode::Floor => self.floor().to_integer(),
+                ::RoundingMode::Ceil => self.ceil().to_integer(),
+                ::RoundingMode::HalfEven => {
+                    let two = <V as ::num::One>::one() + <V as ::num::One>::one();
+
+                    let floor = self.floor().to_integer();
+
+                    // Half-way cases are the only reduced ratios with a denominator of two.
+                    if *self.denom() != two {
+                        self.round().to_integer()
+                    } else if floor.is_even() {
+                        floor
+                    } else {
+                        self.ceil().to_integer()
+                    }
+                }
+            }
+        }
+
+        #[inline(always)]
+        fn try_value(self) -> Result<V, ::ConversionError> {
+            if self.is_integer() {
+                Ok(self.to_integer())
+            } else {
+                Err(::ConversionError::Inexact)
+            }
+        }
     }
 }
 
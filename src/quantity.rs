@@ -11,9 +11,21 @@
 /// * `$system`: System of quantities type (e.g. `ISQ`).
 /// * `$dimension`: Power of a factor for each base quantity in the system. Power should be
 ///   represented as a `typenum` type-level integer (e.g. `N1`, `Z0`, `P1`, `P2`, ...).
+/// * `$kind`: Optional. [Kind][kind] of the quantity. Quantities of different kinds can't be mixed
+///   even when their dimensions are the same. The kind also restricts which operations are
+///   available. Defaults to [`Kind`](trait.Kind.html).
 /// * `$unit`: Unit name (e.g. `meter`, `foot`).
 /// * `$conversion`: Conversion from the unit to the base unit of the quantity (e.g. `3.048E-1` to
 ///   convert `foot` to `meter`).
+/// * `$numer / $denom`: Optional. Exact conversion factor as a ratio of integer literals (e.g.
+///   `3_048 / 10_000` for `foot`). Used instead of `$conversion` for integer, arbitrary width
+///   integer, and integer ratio storage types, which otherwise approximate `$conversion` with a
+///   ratio.
+/// * `$constant`: Optional. Conversion constant added to a value in the unit before the conversion
+///   factor is applied (e.g. `273.15` to convert `degree_celsius` to `kelvin`). See
+///   [`Conversion::constant`](trait.Conversion.html#method.constant).
+/// * `$constant_numer / $constant_denom`: Optional. Exact conversion constant as a ratio of integer
+///   literals (e.g. `27_315 / 100` for `degree_celsius`).
 /// * `$abbreviation`: Unit abbreviation (e.g. `"m"`).
 /// * `$singular`: Singular unit description (e.g. `"meter"`).
 /// * `$plural`: Plural unit description (e.g. `"meters"`).
@@ -22,6 +34,11 @@
 /// system. The `#[macro_use]` attribute must be used when including the `uom` crate to make the
 /// `quantity!` macro available.
 ///
+/// A conversion factor or constant that can't be represented by the underlying storage type (e.g.
+/// the conversion factor of `yottameter` for `i8`) causes [`Conversion::try_conversion`][try] and
+/// [`Conversion::try_constant`][try_constant] to return `None`. `Conversion::conversion` and
+/// `Conversion::constant` panic in this case.
+///
 /// ```
 /// #[macro_use]
 /// extern crate uom;
@@ -37,7 +54,7 @@
 ///         dimension: Q<P1 /*length*/, Z0 /*mass*/, Z0 /*time*/>;
 ///         units {
 ///             @meter: 1.0E0; "m", "meter", "meters";
-///             @foot: 3.048E-1; "ft", "foot", "feet";
+///             @foot: 3.048E-1 => 3_048 / 10_000; "ft", "foot", "feet";
 ///         }
 ///     }
 /// }
@@ -87,18 +104,23 @@
 ///
 /// [quantity]: http://jcgm.bipm.org/vim/en/1.1.html
 /// [measurement]: http://jcgm.bipm.org/vim/en/1.9.html
+/// [kind]: https://jcgm.bipm.org/vim/en/1.2.html
+/// [try]: trait.Conversion.html#method.try_conversion
+/// [try_constant]: trait.Conversion.html#method.try_constant
 #[macro_export]
 macro_rules! quantity {
     (
         $(#[$quantity_attr:meta])* quantity: $quantity:ident; $description:expr;
         $(#[$dim_attr:meta])* dimension: $system:ident<$($dimension:ident),+>;
+        $(kind: $kind:ty;)*
         units {
-            $($(#[$unit_attr:meta])* @$unit:ident: $conversion:expr;
+            $($(#[$unit_attr:meta])* @$unit:ident: $conversion:expr $(=> $numer:tt / $denom:tt)*
+                $(, $constant:expr $(=> $constant_numer:tt / $constant_denom:tt)*)*;
                 $abbreviation:expr, $singular:expr, $plural:expr;)+
         }
     ) => {
         $(#[$dim_attr])*
-        pub type Dimension = super::$system<$($crate::typenum::$dimension),+>;
+        pub type Dimension = super::$system<$($crate::typenum::$dimension,)+ $($kind),*>;
 
         $(#[$quantity_attr])*
         pub type $quantity<U, V> = super::Quantity<Dimension, U, V>;
@@ -149,6 +171,12 @@ macro_rules! quantity {
                 fn conversion() -> Self::T {
                     $conversion
                 }
+
+                #[inline(always)]
+                #[allow(unused_variables)]
+                fn constant(op: $crate::ConstantOp) -> Self::T {
+                    quantity!(@constant op $($constant)*)
+                }
             }
 
             impl super::Conversion<V> for super::$unit {})+
@@ -162,10 +190,26 @@ macro_rules! quantity {
 
                 #[inline(always)]
                 fn conversion() -> Self::T {
-                    use $crate::num::FromPrimitive;
+                    <Self as $crate::Conversion<V>>::try_conversion()
+                        .expect(quantity!(@out_of_range))
+                }
 
-                    Self::T::from_f64($conversion).unwrap()
+                #[inline(always)]
+                fn try_conversion() -> Option<Self::T> {
+                    quantity!(@factor Self::T; $conversion $(=> $numer / $denom)*)
+                }
+
+                $(#[inline(always)]
+                fn constant(op: $crate::ConstantOp) -> Self::T {
+                    <Self as $crate::Conversion<V>>::try_constant(op)
+                        .expect(quantity!(@out_of_range))
                 }
+
+                #[inline(always)]
+                fn try_constant(_op: $crate::ConstantOp) -> Option<Self::T> {
+                    quantity!(@factor Self::T;
+                        $constant $(=> $constant_numer / $constant_denom)*)
+                })*
             }
 
             impl super::Conversion<V> for super::$unit {})+
@@ -179,15 +223,29 @@ macro_rules! quantity {
 
                 #[inline(always)]
                 fn conversion() -> Self::T {
-                    use $crate::num::FromPrimitive;
+                    <Self as $crate::Conversion<V>>::try_conversion()
+                        .expect(quantity!(@out_of_range))
+                }
 
-                    let c = $crate::num::rational::Ratio::<$crate::num::BigInt>::from_f64(
-                            $conversion)
-                        .unwrap();
+                #[inline(always)]
+                fn try_conversion() -> Option<Self::T> {
+                    quantity!(@factor $crate::num::rational::Ratio<$crate::num::BigInt>;
+                            $conversion $(=> $numer / $denom)*)
+                        .and_then(|c| quantity!(@biguint c))
+                }
 
-                    Self::T::new(c.numer().to_biguint().unwrap(),
-                        c.denom().to_biguint().unwrap())
+                $(#[inline(always)]
+                fn constant(op: $crate::ConstantOp) -> Self::T {
+                    <Self as $crate::Conversion<V>>::try_constant(op)
+                        .expect(quantity!(@out_of_range))
                 }
+
+                #[inline(always)]
+                fn try_constant(_op: $crate::ConstantOp) -> Option<Self::T> {
+                    quantity!(@factor $crate::num::rational::Ratio<$crate::num::BigInt>;
+                            $constant $(=> $constant_numer / $constant_denom)*)
+                        .and_then(|c| quantity!(@biguint c))
+                })*
             }
 
             impl super::Conversion<V> for super::$unit {})+
@@ -201,10 +259,26 @@ macro_rules! quantity {
 
                 #[inline(always)]
                 fn conversion() -> Self::T {
-                    use $crate::num::FromPrimitive;
+                    <Self as $crate::Conversion<V>>::try_conversion()
+                        .expect(quantity!(@out_of_range))
+                }
 
-                    Self::T::from_f64($conversion).unwrap()
+                #[inline(always)]
+                fn try_conversion() -> Option<Self::T> {
+                    quantity!(@factor Self::T; $conversion $(=> $numer / $denom)*)
                 }
+
+                $(#[inline(always)]
+                fn constant(op: $crate::ConstantOp) -> Self::T {
+                    <Self as $crate::Conversion<V>>::try_constant(op)
+                        .expect(quantity!(@out_of_range))
+                }
+
+                #[inline(always)]
+                fn try_constant(_op: $crate::ConstantOp) -> Option<Self::T> {
+                    quantity!(@factor Self::T;
+                        $constant $(=> $constant_numer / $constant_denom)*)
+                })*
             }
 
             impl super::Conversion<V> for super::$unit {})+
@@ -217,6 +291,294 @@ macro_rules! quantity {
             $description
         }
 
+        /// Returns `true` if the given string matches the abbreviation, singular, or plural
+        /// description of one of the quantity's measurement units.
+        #[allow(dead_code)]
+        #[inline]
+        pub fn is_unit(unit: &str) -> bool {
+            $(unit == $abbreviation || unit == $singular || unit == $plural)||+
+        }
+
+        /// Descriptors of the quantity's measurement units in the order they are declared. See
+        /// [`UnitDescriptor`](../../struct.UnitDescriptor.html).
+        #[allow(dead_code)]
+        #[inline]
+        pub fn units() -> &'static [$crate::UnitDescriptor] {
+            static UNITS: &'static [$crate::UnitDescriptor] = &[
+                $($crate::UnitDescriptor {
+                    abbreviation: $abbreviation,
+                    singular: $singular,
+                    plural: $plural,
+                    conversion: {
+                        fn conversion() -> f64 { $conversion }
+
+                        conversion
+                    },
+                    constant: {
+                        fn constant() -> f64 { quantity!(@constant_value $($constant)*) }
+
+                        constant
+                    },
+                },)+
+            ];
+
+            UNITS
+        }
+
+        /// Ordered tuple of the quantity's measurement units, from the largest to the smallest,
+        /// used to decompose the quantity into mixed units (e.g. `(foot, inch)`). Implemented for
+        /// tuples of two and three measurement units.
+        pub trait MixedUnits<V>: Copy
+        where
+            V: $crate::num::Float + $crate::Conversion<V>,
+        {
+            /// Values of the decomposed quantity. The integer value in each measurement unit except
+            /// the smallest followed by the remainder in the smallest measurement unit. A remainder
+            /// within rounding error of a whole larger measurement unit is carried into it.
+            type Values: Copy;
+
+            /// Decomposes the quantity into values in the measurement units.
+            fn decompose<U>(self, quantity: $quantity<U, V>) -> Self::Values
+            where
+                U: super::Units<V> + ?Sized;
+
+            /// Creates a quantity from values in the measurement units.
+            fn compose<U>(self, values: Self::Values) -> $quantity<U, V>
+            where
+                U: super::Units<V> + ?Sized;
+
+            /// Formats the values each followed by the abbreviation of the measurement unit. The
+            /// sign of a negative quantity is written once before the first value. Formatting flags
+            /// are applied to the remainder and a remainder that rounds to a whole larger
+            /// measurement unit at the formatting precision is carried into it.
+            fn fmt(
+                self,
+                values: &Self::Values,
+                f: &mut $crate::lib::fmt::Formatter
+            ) -> $crate::lib::fmt::Result
+            where
+                V: $crate::lib::fmt::Display;
+        }
+
+        impl<V, N1, N2> MixedUnits<V> for (N1, N2)
+        where
+            V: $crate::num::Float + $crate::Conversion<V>,
+            N1: Unit + $crate::Conversion<V, T = V::T>,
+            N2: Unit + $crate::Conversion<V, T = V::T>,
+        {
+            type Values = (V, V);
+
+            #[inline]
+            fn decompose<U>(self, quantity: $quantity<U, V>) -> Self::Values
+            where
+                U: super::Units<V> + ?Sized,
+            {
+                let count = mixed_ratio::<V, N1, N2>();
+                let tolerance = mixed_tolerance(quantity.get(self.0), count);
+
+                mixed_carry(quantity.trunc(self.0).get(self.0), quantity.fract(self.0).get(self.1),
+                    count, tolerance)
+            }
+
+            #[inline]
+            fn compose<U>(self, values: Self::Values) -> $quantity<U, V>
+            where
+                U: super::Units<V> + ?Sized,
+            {
+                $quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: $quantity::<U, V>::new::<N1>(values.0).value
+                        + $quantity::<U, V>::new::<N2>(values.1).value,
+                }
+            }
+
+            fn fmt(
+                self,
+                values: &Self::Values,
+                f: &mut $crate::lib::fmt::Formatter
+            ) -> $crate::lib::fmt::Result
+            where
+                V: $crate::lib::fmt::Display,
+            {
+                let (v0, v1) = (values.0.abs(), values.1.abs());
+                let (v0, v1) = mixed_round(v0, v1, mixed_ratio::<V, N1, N2>(), f.precision());
+
+                if values.0 < V::zero() || values.1 < V::zero() {
+                    f.write_str("-")?;
+                }
+
+                write!(f, "{} {} ", v0, N1::abbreviation())?;
+                $crate::lib::fmt::Display::fmt(&v1, f)?;
+                write!(f, " {}", N2::abbreviation())
+            }
+        }
+
+        impl<V, N1, N2, N3> MixedUnits<V> for (N1, N2, N3)
+        where
+            V: $crate::num::Float + $crate::Conversion<V>,
+            N1: Unit + $crate::Conversion<V, T = V::T>,
+            N2: Unit + $crate::Conversion<V, T = V::T>,
+            N3: Unit + $crate::Conversion<V, T = V::T>,
+        {
+            type Values = (V, V, V);
+
+            #[inline]
+            fn decompose<U>(self, quantity: $quantity<U, V>) -> Self::Values
+            where
+                U: super::Units<V> + ?Sized,
+            {
+                let counts = (mixed_ratio::<V, N1, N2>(), mixed_ratio::<V, N2, N3>());
+                let tolerance = mixed_tolerance(quantity.get(self.0), counts.0);
+                let remainder = quantity.fract(self.0);
+                let (v1, v2) = mixed_carry(remainder.trunc(self.1).get(self.1),
+                    remainder.fract(self.1).get(self.2), counts.1, tolerance * counts.1);
+                let (v0, v1) = mixed_carry(quantity.trunc(self.0).get(self.0), v1, counts.0,
+                    tolerance);
+
+                (v0, v1, v2)
+            }
+
+            #[inline]
+            fn compose<U>(self, values: Self::Values) -> $quantity<U, V>
+            where
+                U: super::Units<V> + ?Sized,
+            {
+                $quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: $quantity::<U, V>::new::<N1>(values.0).value
+                        + $quantity::<U, V>::new::<N2>(values.1).value
+                        + $quantity::<U, V>::new::<N3>(values.2).value,
+                }
+            }
+
+            fn fmt(
+                self,
+                values: &Self::Values,
+                f: &mut $crate::lib::fmt::Formatter
+            ) -> $crate::lib::fmt::Result
+            where
+                V: $crate::lib::fmt::Display,
+            {
+                let (v0, v1, v2) = (values.0.abs(), values.1.abs(), values.2.abs());
+                let (v1, v2) = mixed_round(v1, v2, mixed_ratio::<V, N2, N3>(), f.precision());
+                let (v0, v1) = mixed_round(v0, v1, mixed_ratio::<V, N1, N2>(), Some(0));
+
+                if values.0 < V::zero() || values.1 < V::zero() || values.2 < V::zero() {
+                    f.write_str("-")?;
+                }
+
+                write!(f, "{} {} {} {} ", v0, N1::abbreviation(), v1, N2::abbreviation())?;
+                $crate::lib::fmt::Display::fmt(&v2, f)?;
+                write!(f, " {}", N3::abbreviation())
+            }
+        }
+
+        /// Quantity decomposed into mixed units. Created by the `into_mixed` method of the
+        /// quantity. `Display` writes each value followed by the abbreviation of the measurement
+        /// unit (e.g. `5 ft 7 in`) and the sign of a negative quantity once before the first value
+        /// (e.g. `-5 ft 7 in`). Formatting flags are applied to the remainder; rounding the
+        /// remainder to the formatting precision carries into the larger units (e.g. `71.9 in` is
+        /// displayed as `6 ft 0 in` with `{:.0}`).
+        #[derive(Clone, Copy, Debug)]
+        pub struct Mixed<N, V>
+        where
+            N: MixedUnits<V>,
+            V: $crate::num::Float + $crate::Conversion<V>,
+        {
+            /// Measurement units from the largest to the smallest.
+            pub units: N,
+            /// The integer value in each measurement unit except the smallest followed by the
+            /// remainder in the smallest measurement unit.
+            pub values: N::Values,
+        }
+
+        impl<N, V> Mixed<N, V>
+        where
+            N: MixedUnits<V>,
+            V: $crate::num::Float + $crate::Conversion<V>,
+        {
+            /// Creates the quantity from the values in mixed units.
+            #[inline(always)]
+            pub fn into_quantity<U>(self) -> $quantity<U, V>
+            where
+                U: super::Units<V> + ?Sized,
+            {
+                self.units.compose(self.values)
+            }
+        }
+
+        impl<N, V> $crate::lib::fmt::Display for Mixed<N, V>
+        where
+            N: MixedUnits<V>,
+            V: $crate::num::Float + $crate::Conversion<V> + $crate::lib::fmt::Display,
+        {
+            fn fmt(&self, f: &mut $crate::lib::fmt::Formatter) -> $crate::lib::fmt::Result {
+                self.units.fmt(&self.values, f)
+            }
+        }
+
+        /// Number of the smaller measurement unit `S` in one of the larger measurement unit `L`.
+        #[inline(always)]
+        fn mixed_ratio<V, L, S>() -> V
+        where
+            V: $crate::num::Float + $crate::Conversion<V>,
+            L: $crate::Conversion<V, T = V::T>,
+            S: $crate::Conversion<V, T = V::T>,
+        {
+            use $crate::ConversionFactor;
+
+            (L::conversion() / S::conversion()).value()
+        }
+
+        /// Rounding error, in the larger measurement unit, allowed for a remainder computed from a
+        /// quantity with the given value in that unit.
+        #[inline(always)]
+        fn mixed_tolerance<V>(value: V, count: V) -> V
+        where
+            V: $crate::num::Float,
+        {
+            let two = V::one() + V::one();
+
+            V::epsilon() * count * (value.abs() + V::one()) * two * two
+        }
+
+        /// Carries a remainder within `tolerance` of a whole larger measurement unit, `count`
+        /// smaller units, into the value in the larger unit.
+        #[inline(always)]
+        fn mixed_carry<V>(value: V, remainder: V, count: V, tolerance: V) -> (V, V)
+        where
+            V: $crate::num::Float,
+        {
+            if count - remainder.abs() <= tolerance {
+                (value + remainder.signum(), V::zero())
+            } else {
+                (value, remainder)
+            }
+        }
+
+        /// Carries a non-negative remainder that rounds to a whole larger measurement unit at the
+        /// given formatting precision into the value in the larger unit.
+        #[inline(always)]
+        fn mixed_round<V>(value: V, remainder: V, count: V, precision: Option<usize>) -> (V, V)
+        where
+            V: $crate::num::Float,
+        {
+            use $crate::num::NumCast;
+
+            let scale =
+                precision.and_then(|p| <V as NumCast>::from(10.0).map(|s| s.powi(p as i32)));
+            let rounded = scale.map(|scale| (remainder * scale).round() / scale);
+
+            match rounded {
+                Some(rounded) if count - rounded <= mixed_tolerance(V::zero(), count) => {
+                    (value + V::one(), V::zero())
+                }
+                _ => (value, remainder),
+            }
+        }
+
         impl<U, V> $quantity<U, V>
         where
             U: super::Units<V> + ?Sized,
@@ -244,6 +606,64 @@ macro_rules! quantity {
                 super::from_base::<Dimension, U, V, N>(&self.value)
             }
 
+            /// Create a new quantity from the given value and measurement unit. The value is
+            /// rounded using the given rounding mode when the underlying storage type can't
+            /// represent the value in base units exactly.
+            #[inline(always)]
+            pub fn new_rounded<N>(v: V, mode: $crate::RoundingMode) -> Self
+            where
+                N: Unit + $crate::Conversion<V, T = V::T>,
+            {
+                $quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: super::to_base_rounded::<Dimension, U, V, N>(&v, mode),
+                }
+            }
+
+            /// Retrieve the value of the quantity in the given measurement unit. The value is
+            /// rounded using the given rounding mode when the underlying storage type can't
+            /// represent the value in the given unit exactly.
+            #[inline(always)]
+            pub fn get_rounded<N>(&self, _unit: N, mode: $crate::RoundingMode) -> V
+            where
+                N: Unit + $crate::Conversion<V, T = V::T>,
+            {
+                super::from_base_rounded::<Dimension, U, V, N>(&self.value, mode)
+            }
+
+            /// Create a new quantity from the given value and measurement unit. Returns an error
+            /// if the conversion to base units overflows or if the underlying storage type can't
+            /// represent the value in base units exactly.
+            #[inline(always)]
+            pub fn try_new<N>(v: V) -> Result<Self, $crate::ConversionError>
+            where
+                V::T: $crate::num::CheckedAdd + $crate::num::CheckedSub
+                    + $crate::num::CheckedMul + $crate::num::CheckedDiv,
+                N: Unit + $crate::Conversion<V, T = V::T>,
+            {
+                super::try_to_base::<Dimension, U, V, N>(&v).map(|v| {
+                    $quantity {
+                        dimension: $crate::lib::marker::PhantomData,
+                        units: $crate::lib::marker::PhantomData,
+                        value: v,
+                    }
+                })
+            }
+
+            /// Retrieve the value of the quantity in the given measurement unit. Returns an error
+            /// if the conversion overflows or if the underlying storage type can't represent the
+            /// value in the given unit exactly.
+            #[inline(always)]
+            pub fn try_get<N>(&self, _unit: N) -> Result<V, $crate::ConversionError>
+            where
+                V::T: $crate::num::CheckedAdd + $crate::num::CheckedSub
+                    + $crate::num::CheckedMul + $crate::num::CheckedDiv,
+                N: Unit + $crate::Conversion<V, T = V::T>,
+            {
+                super::try_from_base::<Dimension, U, V, N>(&self.value)
+            }
+
             /// Returns the largest integer less than or equal to a number in the given
             /// measurement unit.
             #[inline(always)]
@@ -296,8 +716,307 @@ macro_rules! quantity {
             {
                 Self::new::<N>(self.get(_unit).fract())
             }
+
+            /// Decomposes the quantity into mixed units. `units` is a tuple of measurement units
+            /// ordered from the largest to the smallest (e.g. `(foot, inch)`). The result holds the
+            /// integer value in each measurement unit except the smallest, computed with `trunc`,
+            /// followed by the remainder in the smallest measurement unit, computed with `fract`. A
+            /// remainder within rounding error of a whole larger measurement unit is carried into
+            /// it (e.g. `12 in` is decomposed into `1 ft 0 in`). All values have the sign of the
+            /// quantity. See [`fmt`](../fmt/index.html) for an example.
+            #[inline(always)]
+            pub fn into_mixed<N>(self, units: N) -> Mixed<N, V>
+            where
+                V: $crate::num::Float,
+                N: MixedUnits<V>,
+            {
+                Mixed {
+                    units,
+                    values: units.decompose(self),
+                }
+            }
+
+            /// Creates a quantity from values in mixed units. `units` is a tuple of measurement
+            /// units ordered from the largest to the smallest (e.g. `(foot, inch)`). Inverse of
+            /// [`into_mixed`](#method.into_mixed).
+            #[inline(always)]
+            pub fn from_mixed<N>(units: N, values: N::Values) -> Self
+            where
+                V: $crate::num::Float,
+                N: MixedUnits<V>,
+            {
+                units.compose(values)
+            }
+
+            /// Creates a struct that can be used to format a compatible quantity for display. See
+            /// [`fmt`](../fmt/index.html) for details.
+            #[inline(always)]
+            pub fn format_args<N>(
+                unit: N,
+                style: $crate::fmt::DisplayStyle
+            ) -> super::fmt::Arguments<Dimension, N>
+            where
+                N: Unit,
+            {
+                super::fmt::Arguments {
+                    dimension: $crate::lib::marker::PhantomData,
+                    unit,
+                    style,
+                }
+            }
+
+            /// Creates a struct that formats `self` for display in the given measurement unit. See
+            /// [`fmt`](../fmt/index.html) for details.
+            #[inline(always)]
+            pub fn into_format_args<N>(
+                self,
+                unit: N,
+                style: $crate::fmt::DisplayStyle
+            ) -> super::fmt::QuantityArguments<Dimension, U, V, N>
+            where
+                N: Unit,
+            {
+                super::fmt::QuantityArguments {
+                    arguments: super::fmt::Arguments {
+                        dimension: $crate::lib::marker::PhantomData,
+                        unit,
+                        style,
+                    },
+                    quantity: self,
+                }
+            }
+
+            /// Creates a struct that formats `self` for display in a measurement unit selected
+            /// from the quantity's units so that the value falls within `[1, 1000)` when possible.
+            /// See [`fmt`](../fmt/index.html) for details.
+            #[inline(always)]
+            pub fn into_auto_format_args(
+                self,
+                style: $crate::fmt::DisplayStyle,
+                selection: $crate::fmt::UnitSelection
+            ) -> super::fmt::AutoQuantityArguments<Dimension, U, V> {
+                super::fmt::AutoQuantityArguments {
+                    quantity: self,
+                    units: units(),
+                    style,
+                    selection,
+                }
+            }
+        }
+
+        impl<U, V> $crate::lib::str::FromStr for $quantity<U, V>
+        where
+            U: super::Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::str::FromStr,
+            $($unit: Conversion<V>,)+
+        {
+            type Err = $crate::str::ParseQuantityError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                use $crate::str::ParseQuantityError;
+
+                let mut parts = s.trim().splitn(2, char::is_whitespace);
+                let value = parts.next().unwrap_or("");
+                let unit = parts.next().unwrap_or("").trim();
+                let value = value.parse::<V>().map_err(|_| ParseQuantityError::ValueParseError)?;
+
+                $(if unit == $abbreviation || unit == $singular || unit == $plural {
+                    return Ok(Self::new::<$unit>(value));
+                })+
+
+                // A missing unit isn't a unit of a different quantity even when a quantity of the
+                // system (e.g. a ratio) has a unit with an empty abbreviation.
+                match super::unit_quantity(unit) {
+                    Some(quantity) if !unit.is_empty() => {
+                        Err(ParseQuantityError::DifferentQuantity(quantity))
+                    }
+                    _ => Err(ParseQuantityError::UnknownUnit),
+                }
+            }
+        }
+
+        /// Singular description of the measurement unit matching the given singular or plural
+        /// description or, failing that, abbreviation. Singular descriptions are used to identify
+        /// units because abbreviations are not unique (e.g. `foot` and `foot_survey`). An ambiguous
+        /// abbreviation matches the first unit declared with it.
+        #[cfg(feature = "serde")]
+        #[allow(dead_code)]
+        fn unit_singular(unit: &str) -> Option<&'static str> {
+            $(if unit == $singular || unit == $plural {
+                return Some($singular);
+            })+
+
+            $(if unit == $abbreviation {
+                return Some($singular);
+            })+
+
+            None
+        }
+
+        /// Name used to serialize the measurement unit with the given abbreviation and singular
+        /// description. The abbreviation is used unless another of the quantity's measurement units
+        /// shares it (e.g. `foot` and `foot_survey`), in which case the singular description is
+        /// used so that the unit is identified unambiguously.
+        #[cfg(feature = "serde")]
+        #[allow(dead_code)]
+        fn unit_tag(abbreviation: &'static str, singular: &'static str) -> &'static str {
+            if 1 == [$($abbreviation),+].iter().filter(|&&a| a == abbreviation).count() {
+                abbreviation
+            } else {
+                singular
+            }
+        }
+
+        /// A quantity along with the measurement unit used to serialize it. Serialized as a struct
+        /// containing the value in the measurement unit and the unit's abbreviation (e.g.
+        /// `{"value": 5.0, "unit": "km"}`). Units whose abbreviation is shared with another unit of
+        /// the quantity are serialized with their singular description instead (e.g.
+        /// `"foot (U.S. survey)"`). Deserialization accepts the abbreviation, singular, or plural
+        /// description of any of the quantity's measurement units. See
+        /// [`serde_support`](../../serde_support/index.html).
+        #[cfg(feature = "serde")]
+        #[allow(dead_code)]
+        pub struct Tagged<U, V>
+        where
+            U: super::Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V>,
+        {
+            quantity: $quantity<U, V>,
+            unit: &'static str,
+        }
+
+        #[cfg(feature = "serde")]
+        #[allow(dead_code)]
+        impl<U, V> Tagged<U, V>
+        where
+            U: super::Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V>,
+        {
+            /// Create a tagged quantity that is serialized in the given measurement unit.
+            #[inline(always)]
+            pub fn new<N>(quantity: $quantity<U, V>, _unit: N) -> Self
+            where
+                N: Unit,
+            {
+                Tagged {
+                    quantity,
+                    unit: N::singular(),
+                }
+            }
+
+            /// Abbreviation of the measurement unit used to serialize the quantity.
+            #[inline]
+            pub fn unit(&self) -> &'static str {
+                $(if self.unit == $singular {
+                    return $abbreviation;
+                })+
+
+                unreachable!()
+            }
+
+            /// Unwrap the quantity.
+            #[inline(always)]
+            pub fn into_inner(self) -> $quantity<U, V> {
+                self.quantity
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<U, V> $crate::lib::clone::Clone for Tagged<U, V>
+        where
+            U: super::Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::clone::Clone,
+        {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                Tagged {
+                    quantity: self.quantity.clone(),
+                    unit: self.unit,
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<U, V> $crate::lib::fmt::Debug for Tagged<U, V>
+        where
+            U: super::Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut $crate::lib::fmt::Formatter) -> $crate::lib::fmt::Result {
+                f.debug_struct("Tagged")
+                    .field("quantity", &self.quantity)
+                    .field("unit", &self.unit())
+                    .finish()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<U, V> $crate::serde::Serialize for Tagged<U, V>
+        where
+            U: super::Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::serde_support::SerdeValue,
+            $($unit: Conversion<V>,)+
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::serde::Serializer,
+            {
+                $(if self.unit == $singular {
+                    return $crate::serde_support::serialize_tagged(
+                        serializer, stringify!($quantity), &self.quantity.get($unit),
+                        unit_tag($abbreviation, $singular));
+                })+
+
+                unreachable!()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, U, V> $crate::serde::Deserialize<'de> for Tagged<U, V>
+        where
+            U: super::Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::serde_support::SerdeValue,
+            $($unit: Conversion<V>,)+
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: $crate::serde::Deserializer<'de>,
+            {
+                let (value, unit) = $crate::serde_support::deserialize_tagged::<D, V>(
+                    deserializer, stringify!($quantity), unit_singular)?;
+
+                $(if unit == $singular {
+                    return Ok(Tagged {
+                        quantity: $quantity::new::<$unit>(value),
+                        unit,
+                    });
+                })+
+
+                unreachable!()
+            }
+        }
+    };
+    (@factor $t:ty; $factor:expr) => {
+        <$t as $crate::num::FromPrimitive>::from_f64($factor)
+    };
+    (@factor $t:ty; $factor:expr => $numer:tt / $denom:tt) => {
+        <$t as $crate::num::FromPrimitive>::from_u64($numer)
+            .and_then(|n| <$t as $crate::num::FromPrimitive>::from_u64($denom).map(|d| n / d))
+    };
+    (@biguint $factor:ident) => {
+        $factor.numer().to_biguint()
+            .and_then(|n| $factor.denom().to_biguint().map(|d| Self::T::new(n, d)))
+    };
+    (@out_of_range) => { "conversion factor out of range for the underlying storage type" };
+    (@constant $op:ident $constant:expr) => { $constant };
+    (@constant $op:ident) => {
+        match $op {
+            $crate::ConstantOp::Add => -0.0,
+            $crate::ConstantOp::Sub => 0.0,
         }
     };
+    (@constant_value $constant:expr) => { $constant };
+    (@constant_value) => { 0.0 };
     (@unit $(#[$unit_attr:meta])+ @$unit:ident) => {
         $(#[$unit_attr])*
         #[allow(non_camel_case_types)]
@@ -0,0 +1,322 @@
+//! Support for serializing and deserializing [quantities](http://jcgm.bipm.org/vim/en/1.1.html)
+//! with [`serde`](https://serde.rs/). Enabled by the `serde` feature.
+//!
+//! By default a quantity is serialized as its value in the base units of the system of units. The
+//! `Tagged` struct generated for each quantity (e.g. `uom::si::length::Tagged`) is serialized as a
+//! struct containing the value in a given measurement unit along with the unit's abbreviation
+//! (e.g. `{"value": 5.0, "unit": "km"}`). Abbreviations are not unique and a unit whose
+//! abbreviation is shared with another unit of the same quantity (e.g. `foot` and `foot_survey`)
+//! is serialized with its singular description instead (e.g. `"foot (U.S. survey)"`).
+//! Deserializing a `Tagged` quantity validates the unit against the quantity's measurement units
+//! and accepts the unit's abbreviation, singular, or plural description.
+//!
+#![cfg_attr(all(feature = "si", feature = "f32", feature = "std"), doc = " ```rust")]
+#![cfg_attr(not(all(feature = "si", feature = "f32", feature = "std")), doc = " ```rust,ignore")]
+//! # extern crate serde_json;
+//! # extern crate uom;
+//! # use uom::si::f32::*;
+//! # use uom::si::length::{self, kilometer, meter};
+//! # use uom::si::SI;
+//! # fn main() {
+//! let l = Length::new::<kilometer>(5.0);
+//! let t = length::Tagged::new(l, kilometer);
+//!
+//! assert_eq!("5000.0", serde_json::to_string(&l).unwrap());
+//! assert_eq!(r#"{"value":5.0,"unit":"km"}"#, serde_json::to_string(&t).unwrap());
+//!
+//! let t: length::Tagged<SI<f32>, f32> =
+//!     serde_json::from_str(r#"{"value": 2.0, "unit": "meters"}"#).unwrap();
+//!
+//! assert_eq!(2.0, t.into_inner().get(meter));
+//! assert!(serde_json::from_str::<length::Tagged<SI<f32>, f32>>(
+//!     r#"{"value": 2.0, "unit": "kg"}"#).is_err());
+//! # }
+//! ```
+
+use lib::fmt;
+use lib::marker::PhantomData;
+use lib::str::FromStr;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde::ser::SerializeStruct;
+
+/// Trait to serialize and deserialize an underlying storage type. Floating point and primitive
+/// integer types use their native representation. Arbitrary width integers and integer ratios are
+/// represented as strings (e.g. `"12345678901234567890"`, `"1/3"`).
+pub trait SerdeValue: Sized {
+    /// Serialize the value into the given serializer.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+
+    /// Deserialize a value from the given deserializer.
+    fn deserialize<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+storage_types! {
+    types: Float, PrimInt;
+
+    impl ::serde_support::SerdeValue for V {
+        #[inline(always)]
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ::serde::Serializer,
+        {
+            ::serde::Serialize::serialize(self, serializer)
+        }
+
+        #[inline(always)]
+        fn deserialize<'de, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: ::serde::Deserializer<'de>,
+        {
+            <V as ::serde::Deserialize>::deserialize(deserializer)
+        }
+    }
+}
+
+storage_types! {
+    types: BigInt, BigUint, Ratio;
+
+    impl ::serde_support::SerdeValue for V {
+        #[inline(always)]
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ::serde::Serializer,
+        {
+            serializer.collect_str(self)
+        }
+
+        #[inline(always)]
+        fn deserialize<'de, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: ::serde::Deserializer<'de>,
+        {
+            deserializer
+                .deserialize_str(::serde_support::FromStrVisitor(::lib::marker::PhantomData))
+        }
+    }
+}
+
+/// Visitor to deserialize a value from a string using `FromStr`.
+#[allow(dead_code)]
+struct FromStrVisitor<V>(PhantomData<V>);
+
+impl<'de, V> de::Visitor<'de> for FromStrVisitor<V>
+where
+    V: FromStr,
+{
+    type Value = V;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string containing a number")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        v.parse().map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+/// Wrapper to serialize a value using `SerdeValue`.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct SerializeValue<'a, V: 'a>(pub &'a V);
+
+impl<'a, V> Serialize for SerializeValue<'a, V>
+where
+    V: SerdeValue,
+{
+    #[inline(always)]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Wrapper to deserialize a value using `SerdeValue`.
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct DeserializeValue<V>(pub V);
+
+impl<'de, V> Deserialize<'de> for DeserializeValue<V>
+where
+    V: SerdeValue,
+{
+    #[inline(always)]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        V::deserialize(deserializer).map(DeserializeValue)
+    }
+}
+
+const FIELDS: &[&str] = &["value", "unit"];
+
+/// Serialize a tagged quantity as a struct containing the value and the unit's singular
+/// description.
+#[doc(hidden)]
+pub fn serialize_tagged<S, V>(
+    serializer: S,
+    name: &'static str,
+    value: &V,
+    unit: &'static str,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    V: SerdeValue,
+{
+    let mut state = serializer.serialize_struct(name, 2)?;
+
+    state.serialize_field(FIELDS[0], &SerializeValue(value))?;
+    state.serialize_field(FIELDS[1], unit)?;
+    state.end()
+}
+
+/// Deserialize a tagged quantity's value and unit. `unit` maps a unit abbreviation, singular, or
+/// plural description to the singular description of one of the quantity's measurement units.
+#[doc(hidden)]
+pub fn deserialize_tagged<'de, D, V>(
+    deserializer: D,
+    name: &'static str,
+    unit: fn(&str) -> Option<&'static str>,
+) -> Result<(V, &'static str), D::Error>
+where
+    D: Deserializer<'de>,
+    V: SerdeValue,
+{
+    deserializer.deserialize_struct(name, FIELDS, TaggedVisitor {
+        unit,
+        value: PhantomData,
+    })
+}
+
+enum Field {
+    Value,
+    Unit,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct FieldVisitor;
+
+        impl<'de> de::Visitor<'de> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("`value` or `unit`")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                match v {
+                    "value" => Ok(Field::Value),
+                    "unit" => Ok(Field::Unit),
+                    _ => Err(E::unknown_field(v, FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+/// Seed to deserialize a unit and map it to a unit's singular description.
+struct UnitSeed(fn(&str) -> Option<&'static str>);
+
+impl<'de> de::DeserializeSeed<'de> for UnitSeed {
+    type Value = &'static str;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<'de> de::Visitor<'de> for UnitSeed {
+    type Value = &'static str;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a measurement unit of the quantity")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        (self.0)(v).ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+struct TaggedVisitor<V> {
+    unit: fn(&str) -> Option<&'static str>,
+    value: PhantomData<V>,
+}
+
+impl<'de, V> de::Visitor<'de> for TaggedVisitor<V>
+where
+    V: SerdeValue,
+{
+    type Value = (V, &'static str);
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a struct with a `value` and `unit`")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let value = seq.next_element::<DeserializeValue<V>>()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let unit = seq.next_element_seed(UnitSeed(self.unit))?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        Ok((value.0, unit))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let mut value = None;
+        let mut unit = None;
+
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Value => {
+                    if value.is_some() {
+                        return Err(de::Error::duplicate_field(FIELDS[0]));
+                    }
+
+                    value = Some(map.next_value::<DeserializeValue<V>>()?.0);
+                }
+                Field::Unit => {
+                    if unit.is_some() {
+                        return Err(de::Error::duplicate_field(FIELDS[1]));
+                    }
+
+                    unit = Some(map.next_value_seed(UnitSeed(self.unit))?);
+                }
+            }
+        }
+
+        let value = value.ok_or_else(|| de::Error::missing_field(FIELDS[0]))?;
+        let unit = unit.ok_or_else(|| de::Error::missing_field(FIELDS[1]))?;
+
+        Ok((value, unit))
+    }
+}
@@ -55,6 +55,11 @@ quantity! {
             "zeptometers per second squared";
         @yoctometer_per_second_squared: prefix!(yocto); "ym/s²", "yoctometer per second squared",
             "yoctometers per second squared";
+
+        @foot_per_second_squared: 3.048_E-1; "ft/s²", "foot per second squared",
+            "feet per second squared";
+        @galileo: 1.0_E-2; "Gal", "galileo", "galileos";
+        @standard_gravity: 9.806_65_E0; "gₙ", "standard gravity", "standard gravities";
     }
 }
 
@@ -66,7 +71,6 @@ mod tests {
         use si::acceleration as a;
         use si::length as l;
         use si::time as t;
-        use tests::Test;
 
         #[test]
         fn check_dimension() {
@@ -98,12 +102,32 @@ mod tests {
             test(l::zeptometer, a::zeptometer_per_second_squared);
             test(l::yoctometer, a::yoctometer_per_second_squared);
 
-            // TODO #17 Convert to == once PartialEq is implemented.
-            fn test<L: l::Conversion<V>, A: a::Conversion<V>>(_l: L, a: A) {
-                Test::assert_eq(&V::one(),
-                    &(Length::new::<L>(V::one()) /
-                        (Time::new::<t::second>(V::one()) * Time::new::<t::second>(V::one())))
-                            .get(a));
+            fn test<L: l::Conversion<V>, A: a::Conversion<V>>(_l: L, _a: A) {
+                assert_eq!(Acceleration::new::<A>(V::one()),
+                    Length::new::<L>(V::one()) /
+                        (Time::new::<t::second>(V::one()) * Time::new::<t::second>(V::one())));
+            }
+        }
+    }
+
+    mod float {
+        storage_types! {
+            types: Float;
+
+            use si::quantities::*;
+            use si::acceleration as a;
+            use tests::Test;
+
+            #[test]
+            fn check_units() {
+                Test::assert_approx_eq(&0.304_8,
+                    &Acceleration::new::<a::foot_per_second_squared>(1.0)
+                        .get(a::meter_per_second_squared));
+                Test::assert_approx_eq(&1.0,
+                    &Acceleration::new::<a::galileo>(1.0).get(a::centimeter_per_second_squared));
+                Test::assert_approx_eq(&9.806_65,
+                    &Acceleration::new::<a::standard_gravity>(1.0)
+                        .get(a::meter_per_second_squared));
             }
         }
     }
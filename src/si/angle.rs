@@ -0,0 +1,123 @@
+//! Angle (plane angle, base unit radian, 1).
+
+quantity! {
+    /// Angle (plane angle, base unit radian, 1). Angles are dimensionless but are of a different
+    /// [kind](../../trait.AngleKind.html) than [`Ratio`](../ratio/type.Ratio.html) and
+    /// [`SolidAngle`](../solid_angle/type.SolidAngle.html).
+    ///
+    #[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust,compile_fail")]
+    #[cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+    /// # use uom::si::f32::*;
+    /// # use uom::si::angle::radian;
+    /// # use uom::si::ratio::ratio;
+    /// // error[E0308]: mismatched types
+    /// let a = Angle::new::<radian>(1.0) + Ratio::new::<ratio>(1.0);
+    /// ```
+    quantity: Angle; "angle";
+    /// Angle dimension, 1.
+    dimension: ISQ<
+        Z0,     // length
+        Z0,     // mass
+        Z0,     // time
+        Z0,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    kind: ::si::marker::AngleKind;
+    units {
+        /// The radian is the angle subtended at the center of a circle by an arc that is equal in
+        /// length to the radius of the circle.
+        @radian: 1.0_E0; "rad", "radian", "radians";
+        @degree: 1.745_329_251_994_329_5_E-2; "°", "degree", "degrees";
+        @gradian: 1.570_796_326_794_896_6_E-2; "gon", "gradian", "gradians";
+        @revolution: 3.6_E2 * 1.745_329_251_994_329_5_E-2; "r", "revolution", "revolutions";
+        @arcminute: 2.908_882_086_657_216_E-4; "′", "arcminute", "arcminutes";
+        @arcsecond: 4.848_136_811_095_36_E-6; "″", "arcsecond", "arcseconds";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        types: Float;
+
+        use num::traits::FloatConst;
+        use quickcheck::TestResult;
+        use si::quantities::*;
+        use si::angle as a;
+        use si::length as l;
+        use si::ratio as r;
+        use tests::Test;
+
+        #[test]
+        fn check_dimension() {
+            let _: Angle<V> = Angle::new::<a::radian>(1.0) + Angle::new::<a::degree>(1.0);
+            let _: Length<V> = Angle::new::<a::radian>(1.0) * Length::new::<l::meter>(1.0);
+        }
+
+        #[test]
+        fn check_units() {
+            Test::assert_approx_eq(&(2.0 * V::PI()),
+                &Angle::new::<a::revolution>(1.0).get(a::radian));
+            Test::assert_approx_eq(&360.0, &Angle::new::<a::revolution>(1.0).get(a::degree));
+            Test::assert_approx_eq(&400.0, &Angle::new::<a::revolution>(1.0).get(a::gradian));
+            Test::assert_approx_eq(&60.0, &Angle::new::<a::degree>(1.0).get(a::arcminute));
+            Test::assert_approx_eq(&60.0, &Angle::new::<a::arcminute>(1.0).get(a::arcsecond));
+        }
+
+        quickcheck! {
+            #[allow(trivial_casts)]
+            fn sin(v: V) -> bool {
+                Test::eq(&v.sin(), &Angle::new::<a::radian>(v).sin().get(r::ratio))
+            }
+
+            #[allow(trivial_casts)]
+            fn cos(v: V) -> bool {
+                Test::eq(&v.cos(), &Angle::new::<a::radian>(v).cos().get(r::ratio))
+            }
+
+            #[allow(trivial_casts)]
+            fn tan(v: V) -> bool {
+                Test::eq(&v.tan(), &Angle::new::<a::radian>(v).tan().get(r::ratio))
+            }
+
+            #[allow(trivial_casts)]
+            fn sin_cos(v: V) -> bool {
+                let (sin, cos) = Angle::new::<a::radian>(v).sin_cos();
+
+                Test::eq(&v.sin(), &sin.get(r::ratio)) && Test::eq(&v.cos(), &cos.get(r::ratio))
+            }
+
+            #[allow(trivial_casts)]
+            fn asin(v: V) -> TestResult {
+                if v.abs() > 1.0 {
+                    return TestResult::discard();
+                }
+
+                TestResult::from_bool(
+                    Test::eq(&v.asin(), &Ratio::new::<r::ratio>(v).asin().get(a::radian)))
+            }
+
+            #[allow(trivial_casts)]
+            fn acos(v: V) -> TestResult {
+                if v.abs() > 1.0 {
+                    return TestResult::discard();
+                }
+
+                TestResult::from_bool(
+                    Test::eq(&v.acos(), &Ratio::new::<r::ratio>(v).acos().get(a::radian)))
+            }
+
+            #[allow(trivial_casts)]
+            fn atan(v: V) -> bool {
+                Test::eq(&v.atan(), &Ratio::new::<r::ratio>(v).atan().get(a::radian))
+            }
+
+            #[allow(trivial_casts)]
+            fn atan2(y: V, x: V) -> bool {
+                Test::eq(&y.atan2(x),
+                    &Length::new::<l::meter>(y).atan2(Length::new::<l::meter>(x)).get(a::radian))
+            }
+        }
+    }
+}
@@ -55,6 +55,14 @@ quantity! {
             "zm²", "square zeptometer", "square zeptometers";
         @square_yoctometer: prefix!(yocto) * prefix!(yocto);
             "ym²", "square yoctometer", "square yoctometers";
+
+        @acre: 4.046_873_E3; "ac", "acre", "acres";
+        @barn: 1.0_E-28; "b", "barn", "barns";
+        @hectare: 1.0_E4; "ha", "hectare", "hectares";
+        @square_foot: 9.290_304_E-2; "ft²", "square foot", "square feet";
+        @square_inch: 6.451_6_E-4; "in²", "square inch", "square inches";
+        @square_mile: 2.589_988_E6; "mi²", "square mile", "square miles";
+        @square_yard: 8.361_273_6_E-1; "yd²", "square yard", "square yards";
     }
 }
 
@@ -66,7 +74,6 @@ mod tests {
         use si::quantities::*;
         use si::area as a;
         use si::length as l;
-        use tests::Test;
 
         #[test]
         fn check_dimension() {
@@ -105,10 +112,35 @@ mod tests {
                 test(l::yoctometer, a::square_yoctometer);
             }
 
-            // TODO #17 Convert to == once PartialEq is implemented.
-            fn test<L: l::Conversion<V>, A: a::Conversion<V>>(_l: L, a: A) {
-                Test::assert_eq(&V::one(),
-                    &(Length::new::<L>(V::one()) * Length::new::<L>(V::one())).get(a));
+            fn test<L: l::Conversion<V>, A: a::Conversion<V>>(_l: L, _a: A) {
+                assert_eq!(Area::new::<A>(V::one()),
+                    Length::new::<L>(V::one()) * Length::new::<L>(V::one()));
+            }
+        }
+    }
+
+    mod float {
+        storage_types! {
+            types: Float;
+
+            use si::quantities::*;
+            use si::area as a;
+            use tests::Test;
+
+            #[test]
+            fn check_units() {
+                Test::assert_approx_eq(&4_046.873, &Area::new::<a::acre>(1.0).get(a::square_meter));
+                Test::assert_approx_eq(&100.0,
+                    &Area::new::<a::barn>(1.0).get(a::square_femtometer));
+                Test::assert_approx_eq(&10_000.0,
+                    &Area::new::<a::hectare>(1.0).get(a::square_meter));
+                Test::assert_approx_eq(&144.0,
+                    &Area::new::<a::square_foot>(1.0).get(a::square_inch));
+                Test::assert_approx_eq(&6.451_6,
+                    &Area::new::<a::square_inch>(1.0).get(a::square_centimeter));
+                Test::assert_approx_eq(&2.589_988,
+                    &Area::new::<a::square_mile>(1.0).get(a::square_kilometer));
+                Test::assert_approx_eq(&9.0, &Area::new::<a::square_yard>(1.0).get(a::square_foot));
             }
         }
     }
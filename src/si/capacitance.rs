@@ -0,0 +1,93 @@
+//! Capacitance (base unit farad, kg<sup>-1</sup> · m<sup>-2</sup> · s<sup>4</sup> · A<sup>2</sup>).
+
+quantity! {
+    /// Capacitance (base unit farad, kg<sup>-1</sup> · m<sup>-2</sup> · s<sup>4</sup> ·
+    /// A<sup>2</sup>).
+    quantity: Capacitance; "capacitance";
+    /// Capacitance dimension, kg<sup>-1</sup> · m<sup>-2</sup> · s<sup>4</sup> · A<sup>2</sup>.
+    dimension: ISQ<
+        N2,     // length
+        N1,     // mass
+        P4,     // time
+        P2,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    units {
+        @yottafarad: prefix!(yotta); "YF", "yottafarad", "yottafarads";
+        @zettafarad: prefix!(zetta); "ZF", "zettafarad", "zettafarads";
+        @exafarad: prefix!(exa); "EF", "exafarad", "exafarads";
+        @petafarad: prefix!(peta); "PF", "petafarad", "petafarads";
+        @terafarad: prefix!(tera); "TF", "terafarad", "terafarads";
+        @gigafarad: prefix!(giga); "GF", "gigafarad", "gigafarads";
+        @megafarad: prefix!(mega); "MF", "megafarad", "megafarads";
+        @kilofarad: prefix!(kilo); "kF", "kilofarad", "kilofarads";
+        @hectofarad: prefix!(hecto); "hF", "hectofarad", "hectofarads";
+        @decafarad: prefix!(deca); "daF", "decafarad", "decafarads";
+        /// Derived unit of capacitance.
+        @farad: prefix!(none); "F", "farad", "farads";
+        @decifarad: prefix!(deci); "dF", "decifarad", "decifarads";
+        @centifarad: prefix!(centi); "cF", "centifarad", "centifarads";
+        @millifarad: prefix!(milli); "mF", "millifarad", "millifarads";
+        @microfarad: prefix!(micro); "µF", "microfarad", "microfarads";
+        @nanofarad: prefix!(nano); "nF", "nanofarad", "nanofarads";
+        @picofarad: prefix!(pico); "pF", "picofarad", "picofarads";
+        @femtofarad: prefix!(femto); "fF", "femtofarad", "femtofarads";
+        @attofarad: prefix!(atto); "aF", "attofarad", "attofarads";
+        @zeptofarad: prefix!(zepto); "zF", "zeptofarad", "zeptofarads";
+        @yoctofarad: prefix!(yocto); "yF", "yoctofarad", "yoctofarads";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        use num::One;
+        use si::quantities::*;
+        use si::capacitance as c;
+        use si::electric_charge as q;
+        use si::electric_potential as v;
+
+        #[test]
+        fn check_dimension() {
+            let _: Capacitance<V> = ElectricCharge::new::<q::coulomb>(V::one())
+                / ElectricPotential::new::<v::volt>(V::one());
+        }
+
+        #[test]
+        fn check_units() {
+            test(q::yottacoulomb, c::yottafarad);
+            test(q::zettacoulomb, c::zettafarad);
+            test(q::exacoulomb, c::exafarad);
+            test(q::petacoulomb, c::petafarad);
+            test(q::teracoulomb, c::terafarad);
+            test(q::gigacoulomb, c::gigafarad);
+            test(q::megacoulomb, c::megafarad);
+            test(q::kilocoulomb, c::kilofarad);
+            test(q::hectocoulomb, c::hectofarad);
+            test(q::decacoulomb, c::decafarad);
+            test(q::coulomb, c::farad);
+            test(q::decicoulomb, c::decifarad);
+            test(q::centicoulomb, c::centifarad);
+            test(q::millicoulomb, c::millifarad);
+            test(q::microcoulomb, c::microfarad);
+            test(q::nanocoulomb, c::nanofarad);
+            test(q::picocoulomb, c::picofarad);
+            test(q::femtocoulomb, c::femtofarad);
+            test(q::attocoulomb, c::attofarad);
+            test(q::zeptocoulomb, c::zeptofarad);
+            test(q::yoctocoulomb, c::yoctofarad);
+
+            fn test<A: q::Conversion<V>, B: c::Conversion<V>>(_a: A, _b: B) {
+                // Skip prefixes that are out of range for the storage type.
+                if <A as ::Conversion<V>>::try_conversion().is_some()
+                    && <B as ::Conversion<V>>::try_conversion().is_some()
+                {
+                    assert_eq!(Capacitance::new::<B>(V::one()),
+                        ElectricCharge::new::<A>(V::one())
+                            / ElectricPotential::new::<v::volt>(V::one()));
+                }
+            }
+        }
+    }
+}
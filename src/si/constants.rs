@@ -0,0 +1,150 @@
+//! Physical constants as [quantities](http://jcgm.bipm.org/vim/en/1.1.html) in the SI.
+//!
+//! Constants are defined for each floating point storage type in the `f32` and `f64` modules. The
+//! seven defining constants of the SI (2019 redefinition) are exact. The values of measured
+//! constants are the [CODATA 2018](https://physics.nist.gov/cuu/Constants/) recommended values and
+//! are documented along with their relative standard uncertainty.
+//!
+#![cfg_attr(all(feature = "si", feature = "f64"), doc = " ```rust")]
+#![cfg_attr(not(all(feature = "si", feature = "f64")), doc = " ```rust,ignore")]
+//! # use uom::si::f64::*;
+//! # use uom::si::constants::f64::{SPEED_OF_LIGHT, STANDARD_GRAVITY};
+//! # use uom::si::time::second;
+//! # use uom::si::velocity::kilometer_per_second;
+//! let v: Velocity = STANDARD_GRAVITY * Time::new::<second>(10.0);
+//!
+//! assert!(v < SPEED_OF_LIGHT);
+//! assert_eq!(299_792.458, SPEED_OF_LIGHT.get(kilometer_per_second));
+//! ```
+
+#![cfg_attr(feature = "cargo-clippy", allow(excessive_precision))]
+
+storage_types! {
+    /// Physical constants for the storage type.
+    pub types: Float;
+
+    use lib::marker::PhantomData;
+    use si::{Quantity, ISQ, SI};
+    use si::quantities::*;
+    use typenum::{N1, N2, P1, P2, P3, Z0};
+
+    /// Hyperfine transition frequency of the caesium 133 atom, Δν<sub>Cs</sub>. Exact.
+    pub const HYPERFINE_TRANSITION_FREQUENCY: Frequency<V> = Quantity {
+        dimension: PhantomData,
+        units: PhantomData,
+        value: 9_192_631_770.0,
+    };
+
+    /// Speed of light in vacuum, c. Exact.
+    pub const SPEED_OF_LIGHT: Velocity<V> = Quantity {
+        dimension: PhantomData,
+        units: PhantomData,
+        value: 299_792_458.0,
+    };
+
+    /// Planck constant, h (J · s). Exact.
+    pub const PLANCK_CONSTANT: Quantity<ISQ<P2, P1, N1, Z0, Z0, Z0, Z0>, SI<V>, V> = Quantity {
+        dimension: PhantomData,
+        units: PhantomData,
+        value: 6.626_070_15_E-34,
+    };
+
+    /// Elementary charge, e. Exact.
+    pub const ELEMENTARY_CHARGE: ElectricCharge<V> = Quantity {
+        dimension: PhantomData,
+        units: PhantomData,
+        value: 1.602_176_634_E-19,
+    };
+
+    /// Boltzmann constant, k (J/K). Exact.
+    pub const BOLTZMANN_CONSTANT: Quantity<ISQ<P2, P1, N2, Z0, N1, Z0, Z0>, SI<V>, V> =
+        Quantity {
+            dimension: PhantomData,
+            units: PhantomData,
+            value: 1.380_649_E-23,
+        };
+
+    /// Avogadro constant, N<sub>A</sub> (mol<sup>-1</sup>). Exact.
+    pub const AVOGADRO_CONSTANT: Quantity<ISQ<Z0, Z0, Z0, Z0, Z0, N1, Z0>, SI<V>, V> = Quantity {
+        dimension: PhantomData,
+        units: PhantomData,
+        value: 6.022_140_76_E23,
+    };
+
+    /// Luminous efficacy of monochromatic radiation of frequency 540 × 10<sup>12</sup> Hz,
+    /// K<sub>cd</sub> (lm/W). Exact.
+    pub const LUMINOUS_EFFICACY: Quantity<ISQ<N2, N1, P3, Z0, Z0, Z0, P1>, SI<V>, V> = Quantity {
+        dimension: PhantomData,
+        units: PhantomData,
+        value: 683.0,
+    };
+
+    /// Standard acceleration of gravity, g<sub>n</sub>. Exact by definition.
+    pub const STANDARD_GRAVITY: Acceleration<V> = Quantity {
+        dimension: PhantomData,
+        units: PhantomData,
+        value: 9.806_65,
+    };
+
+    /// Newtonian constant of gravitation, G (m<sup>3</sup> · kg<sup>-1</sup> · s<sup>-2</sup>).
+    /// Relative standard uncertainty 2.2 × 10<sup>-5</sup>.
+    pub const GRAVITATIONAL_CONSTANT: Quantity<ISQ<P3, N1, N2, Z0, Z0, Z0, Z0>, SI<V>, V> =
+        Quantity {
+            dimension: PhantomData,
+            units: PhantomData,
+            value: 6.674_30_E-11,
+        };
+
+    /// Electron mass, m<sub>e</sub>. Relative standard uncertainty 3.0 × 10<sup>-10</sup>.
+    pub const ELECTRON_MASS: Mass<V> = Quantity {
+        dimension: PhantomData,
+        units: PhantomData,
+        value: 9.109_383_701_5_E-31,
+    };
+
+    /// Proton mass, m<sub>p</sub>. Relative standard uncertainty 3.1 × 10<sup>-10</sup>.
+    pub const PROTON_MASS: Mass<V> = Quantity {
+        dimension: PhantomData,
+        units: PhantomData,
+        value: 1.672_621_923_69_E-27,
+    };
+
+    /// Fine-structure constant, α. Relative standard uncertainty 1.5 × 10<sup>-10</sup>.
+    pub const FINE_STRUCTURE_CONSTANT: Ratio<V> = Quantity {
+        dimension: PhantomData,
+        units: PhantomData,
+        value: 7.297_352_569_3_E-3,
+    };
+
+    #[cfg(test)]
+    mod tests {
+        use si::quantities::*;
+        use si::energy as e;
+        use si::force as f;
+        use si::length as l;
+        use si::mass as m;
+        use si::thermodynamic_temperature as tt;
+        use tests::Test;
+        use super::*;
+
+        #[test]
+        fn check_dimension() {
+            let _: Energy<V> = PLANCK_CONSTANT * HYPERFINE_TRANSITION_FREQUENCY;
+            let _: Energy<V> = BOLTZMANN_CONSTANT
+                * ThermodynamicTemperature::new::<tt::kelvin>(1.0);
+            let _: Energy<V> = ELECTRON_MASS * SPEED_OF_LIGHT * SPEED_OF_LIGHT;
+            let _: Force<V> = GRAVITATIONAL_CONSTANT * PROTON_MASS * PROTON_MASS
+                / (Length::new::<l::meter>(1.0) * Length::new::<l::meter>(1.0));
+            let _: Force<V> = STANDARD_GRAVITY * Mass::new::<m::kilogram>(1.0);
+        }
+
+        #[test]
+        fn check_values() {
+            Test::assert_approx_eq(&9.806_65,
+                &(STANDARD_GRAVITY * Mass::new::<m::kilogram>(1.0)).get(f::newton));
+            Test::assert_approx_eq(&8.987_551_787_368_176_E16,
+                &(Mass::new::<m::kilogram>(1.0) * SPEED_OF_LIGHT * SPEED_OF_LIGHT)
+                    .get(e::joule));
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//! Electric charge (base unit coulomb, A · s).
+
+quantity! {
+    /// Electric charge (base unit coulomb, A · s).
+    quantity: ElectricCharge; "electric charge";
+    /// Electric charge dimension, A · s.
+    dimension: ISQ<
+        Z0,     // length
+        Z0,     // mass
+        P1,     // time
+        P1,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    units {
+        @yottacoulomb: prefix!(yotta); "YC", "yottacoulomb", "yottacoulombs";
+        @zettacoulomb: prefix!(zetta); "ZC", "zettacoulomb", "zettacoulombs";
+        @exacoulomb: prefix!(exa); "EC", "exacoulomb", "exacoulombs";
+        @petacoulomb: prefix!(peta); "PC", "petacoulomb", "petacoulombs";
+        @teracoulomb: prefix!(tera); "TC", "teracoulomb", "teracoulombs";
+        @gigacoulomb: prefix!(giga); "GC", "gigacoulomb", "gigacoulombs";
+        @megacoulomb: prefix!(mega); "MC", "megacoulomb", "megacoulombs";
+        @kilocoulomb: prefix!(kilo); "kC", "kilocoulomb", "kilocoulombs";
+        @hectocoulomb: prefix!(hecto); "hC", "hectocoulomb", "hectocoulombs";
+        @decacoulomb: prefix!(deca); "daC", "decacoulomb", "decacoulombs";
+        /// Derived unit of electric charge.
+        @coulomb: prefix!(none); "C", "coulomb", "coulombs";
+        @decicoulomb: prefix!(deci); "dC", "decicoulomb", "decicoulombs";
+        @centicoulomb: prefix!(centi); "cC", "centicoulomb", "centicoulombs";
+        @millicoulomb: prefix!(milli); "mC", "millicoulomb", "millicoulombs";
+        @microcoulomb: prefix!(micro); "µC", "microcoulomb", "microcoulombs";
+        @nanocoulomb: prefix!(nano); "nC", "nanocoulomb", "nanocoulombs";
+        @picocoulomb: prefix!(pico); "pC", "picocoulomb", "picocoulombs";
+        @femtocoulomb: prefix!(femto); "fC", "femtocoulomb", "femtocoulombs";
+        @attocoulomb: prefix!(atto); "aC", "attocoulomb", "attocoulombs";
+        @zeptocoulomb: prefix!(zepto); "zC", "zeptocoulomb", "zeptocoulombs";
+        @yoctocoulomb: prefix!(yocto); "yC", "yoctocoulomb", "yoctocoulombs";
+
+        @ampere_hour: 3.6_E3; "A · h", "ampere hour", "ampere hours";
+        @milliampere_hour: 3.6_E0; "mA · h", "milliampere hour", "milliampere hours";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        use num::One;
+        use si::quantities::*;
+        use si::electric_charge as q;
+        use si::electric_current as i;
+        use si::time as t;
+
+        #[test]
+        fn check_dimension() {
+            let _: ElectricCharge<V> = ElectricCurrent::new::<i::ampere>(V::one())
+                * Time::new::<t::second>(V::one());
+        }
+
+        #[test]
+        fn check_units() {
+            test(i::yottaampere, q::yottacoulomb);
+            test(i::zettaampere, q::zettacoulomb);
+            test(i::exaampere, q::exacoulomb);
+            test(i::petaampere, q::petacoulomb);
+            test(i::teraampere, q::teracoulomb);
+            test(i::gigaampere, q::gigacoulomb);
+            test(i::megaampere, q::megacoulomb);
+            test(i::kiloampere, q::kilocoulomb);
+            test(i::hectoampere, q::hectocoulomb);
+            test(i::decaampere, q::decacoulomb);
+            test(i::ampere, q::coulomb);
+            test(i::deciampere, q::decicoulomb);
+            test(i::centiampere, q::centicoulomb);
+            test(i::milliampere, q::millicoulomb);
+            test(i::microampere, q::microcoulomb);
+            test(i::nanoampere, q::nanocoulomb);
+            test(i::picoampere, q::picocoulomb);
+            test(i::femtoampere, q::femtocoulomb);
+            test(i::attoampere, q::attocoulomb);
+            test(i::zeptoampere, q::zeptocoulomb);
+            test(i::yoctoampere, q::yoctocoulomb);
+
+            fn test<A: i::Conversion<V>, B: q::Conversion<V>>(_a: A, _b: B) {
+                // Skip prefixes that are out of range for the storage type.
+                if <A as ::Conversion<V>>::try_conversion().is_some()
+                    && <B as ::Conversion<V>>::try_conversion().is_some()
+                {
+                    assert_eq!(ElectricCharge::new::<B>(V::one()),
+                        ElectricCurrent::new::<A>(V::one()) * Time::new::<t::second>(V::one()));
+                }
+            }
+        }
+    }
+
+    mod float {
+        storage_types! {
+            types: Float;
+
+            use si::quantities::*;
+            use si::electric_charge as q;
+            use tests::Test;
+
+            #[test]
+            fn check_units() {
+                Test::assert_approx_eq(&3_600.0,
+                    &ElectricCharge::new::<q::ampere_hour>(1.0).get(q::coulomb));
+                Test::assert_approx_eq(&1_000.0,
+                    &ElectricCharge::new::<q::ampere_hour>(1.0).get(q::milliampere_hour));
+            }
+        }
+    }
+}
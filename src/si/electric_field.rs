@@ -0,0 +1,90 @@
+//! Electric field (base unit volt per meter, kg · m · s<sup>-3</sup> · A<sup>-1</sup>).
+
+quantity! {
+    /// Electric field (base unit volt per meter, kg · m · s<sup>-3</sup> · A<sup>-1</sup>).
+    quantity: ElectricField; "electric field";
+    /// Electric field dimension, kg · m · s<sup>-3</sup> · A<sup>-1</sup>.
+    dimension: ISQ<
+        P1,     // length
+        P1,     // mass
+        N3,     // time
+        N1,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    units {
+        @yottavolt_per_meter: prefix!(yotta); "YV/m", "yottavolt per meter", "yottavolts per meter";
+        @zettavolt_per_meter: prefix!(zetta); "ZV/m", "zettavolt per meter", "zettavolts per meter";
+        @exavolt_per_meter: prefix!(exa); "EV/m", "exavolt per meter", "exavolts per meter";
+        @petavolt_per_meter: prefix!(peta); "PV/m", "petavolt per meter", "petavolts per meter";
+        @teravolt_per_meter: prefix!(tera); "TV/m", "teravolt per meter", "teravolts per meter";
+        @gigavolt_per_meter: prefix!(giga); "GV/m", "gigavolt per meter", "gigavolts per meter";
+        @megavolt_per_meter: prefix!(mega); "MV/m", "megavolt per meter", "megavolts per meter";
+        @kilovolt_per_meter: prefix!(kilo); "kV/m", "kilovolt per meter", "kilovolts per meter";
+        @hectovolt_per_meter: prefix!(hecto); "hV/m", "hectovolt per meter", "hectovolts per meter";
+        @decavolt_per_meter: prefix!(deca); "daV/m", "decavolt per meter", "decavolts per meter";
+        @volt_per_meter: prefix!(none); "V/m", "volt per meter", "volts per meter";
+        @decivolt_per_meter: prefix!(deci); "dV/m", "decivolt per meter", "decivolts per meter";
+        @centivolt_per_meter: prefix!(centi); "cV/m", "centivolt per meter", "centivolts per meter";
+        @millivolt_per_meter: prefix!(milli); "mV/m", "millivolt per meter", "millivolts per meter";
+        @microvolt_per_meter: prefix!(micro); "µV/m", "microvolt per meter", "microvolts per meter";
+        @nanovolt_per_meter: prefix!(nano); "nV/m", "nanovolt per meter", "nanovolts per meter";
+        @picovolt_per_meter: prefix!(pico); "pV/m", "picovolt per meter", "picovolts per meter";
+        @femtovolt_per_meter: prefix!(femto); "fV/m", "femtovolt per meter", "femtovolts per meter";
+        @attovolt_per_meter: prefix!(atto); "aV/m", "attovolt per meter", "attovolts per meter";
+        @zeptovolt_per_meter: prefix!(zepto); "zV/m", "zeptovolt per meter", "zeptovolts per meter";
+        @yoctovolt_per_meter: prefix!(yocto); "yV/m", "yoctovolt per meter", "yoctovolts per meter";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        use num::One;
+        use si::quantities::*;
+        use si::electric_field as e;
+        use si::electric_potential as v;
+        use si::length as l;
+
+        #[test]
+        fn check_dimension() {
+            let _: ElectricField<V> = ElectricPotential::new::<v::volt>(V::one())
+                / Length::new::<l::meter>(V::one());
+        }
+
+        #[test]
+        fn check_units() {
+            test(v::yottavolt, e::yottavolt_per_meter);
+            test(v::zettavolt, e::zettavolt_per_meter);
+            test(v::exavolt, e::exavolt_per_meter);
+            test(v::petavolt, e::petavolt_per_meter);
+            test(v::teravolt, e::teravolt_per_meter);
+            test(v::gigavolt, e::gigavolt_per_meter);
+            test(v::megavolt, e::megavolt_per_meter);
+            test(v::kilovolt, e::kilovolt_per_meter);
+            test(v::hectovolt, e::hectovolt_per_meter);
+            test(v::decavolt, e::decavolt_per_meter);
+            test(v::volt, e::volt_per_meter);
+            test(v::decivolt, e::decivolt_per_meter);
+            test(v::centivolt, e::centivolt_per_meter);
+            test(v::millivolt, e::millivolt_per_meter);
+            test(v::microvolt, e::microvolt_per_meter);
+            test(v::nanovolt, e::nanovolt_per_meter);
+            test(v::picovolt, e::picovolt_per_meter);
+            test(v::femtovolt, e::femtovolt_per_meter);
+            test(v::attovolt, e::attovolt_per_meter);
+            test(v::zeptovolt, e::zeptovolt_per_meter);
+            test(v::yoctovolt, e::yoctovolt_per_meter);
+
+            fn test<A: v::Conversion<V>, B: e::Conversion<V>>(_a: A, _b: B) {
+                // Skip prefixes that are out of range for the storage type.
+                if <A as ::Conversion<V>>::try_conversion().is_some()
+                    && <B as ::Conversion<V>>::try_conversion().is_some()
+                {
+                    assert_eq!(ElectricField::new::<B>(V::one()),
+                        ElectricPotential::new::<A>(V::one()) / Length::new::<l::meter>(V::one()));
+                }
+            }
+        }
+    }
+}
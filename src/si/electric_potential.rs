@@ -0,0 +1,91 @@
+//! Electric potential (base unit volt, kg · m<sup>2</sup> · s<sup>-3</sup> · A<sup>-1</sup>).
+
+quantity! {
+    /// Electric potential (base unit volt, kg · m<sup>2</sup> · s<sup>-3</sup> · A<sup>-1</sup>).
+    quantity: ElectricPotential; "electric potential";
+    /// Electric potential dimension, kg · m<sup>2</sup> · s<sup>-3</sup> · A<sup>-1</sup>.
+    dimension: ISQ<
+        P2,     // length
+        P1,     // mass
+        N3,     // time
+        N1,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    units {
+        @yottavolt: prefix!(yotta); "YV", "yottavolt", "yottavolts";
+        @zettavolt: prefix!(zetta); "ZV", "zettavolt", "zettavolts";
+        @exavolt: prefix!(exa); "EV", "exavolt", "exavolts";
+        @petavolt: prefix!(peta); "PV", "petavolt", "petavolts";
+        @teravolt: prefix!(tera); "TV", "teravolt", "teravolts";
+        @gigavolt: prefix!(giga); "GV", "gigavolt", "gigavolts";
+        @megavolt: prefix!(mega); "MV", "megavolt", "megavolts";
+        @kilovolt: prefix!(kilo); "kV", "kilovolt", "kilovolts";
+        @hectovolt: prefix!(hecto); "hV", "hectovolt", "hectovolts";
+        @decavolt: prefix!(deca); "daV", "decavolt", "decavolts";
+        /// Derived unit of electric potential.
+        @volt: prefix!(none); "V", "volt", "volts";
+        @decivolt: prefix!(deci); "dV", "decivolt", "decivolts";
+        @centivolt: prefix!(centi); "cV", "centivolt", "centivolts";
+        @millivolt: prefix!(milli); "mV", "millivolt", "millivolts";
+        @microvolt: prefix!(micro); "µV", "microvolt", "microvolts";
+        @nanovolt: prefix!(nano); "nV", "nanovolt", "nanovolts";
+        @picovolt: prefix!(pico); "pV", "picovolt", "picovolts";
+        @femtovolt: prefix!(femto); "fV", "femtovolt", "femtovolts";
+        @attovolt: prefix!(atto); "aV", "attovolt", "attovolts";
+        @zeptovolt: prefix!(zepto); "zV", "zeptovolt", "zeptovolts";
+        @yoctovolt: prefix!(yocto); "yV", "yoctovolt", "yoctovolts";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        use num::One;
+        use si::quantities::*;
+        use si::electric_current as i;
+        use si::electric_potential as v;
+        use si::power as p;
+
+        #[test]
+        fn check_dimension() {
+            let _: ElectricPotential<V> = Power::new::<p::watt>(V::one())
+                / ElectricCurrent::new::<i::ampere>(V::one());
+        }
+
+        #[test]
+        fn check_units() {
+            test(p::yottawatt, v::yottavolt);
+            test(p::zettawatt, v::zettavolt);
+            test(p::exawatt, v::exavolt);
+            test(p::petawatt, v::petavolt);
+            test(p::terawatt, v::teravolt);
+            test(p::gigawatt, v::gigavolt);
+            test(p::megawatt, v::megavolt);
+            test(p::kilowatt, v::kilovolt);
+            test(p::hectowatt, v::hectovolt);
+            test(p::decawatt, v::decavolt);
+            test(p::watt, v::volt);
+            test(p::deciwatt, v::decivolt);
+            test(p::centiwatt, v::centivolt);
+            test(p::milliwatt, v::millivolt);
+            test(p::microwatt, v::microvolt);
+            test(p::nanowatt, v::nanovolt);
+            test(p::picowatt, v::picovolt);
+            test(p::femtowatt, v::femtovolt);
+            test(p::attowatt, v::attovolt);
+            test(p::zeptowatt, v::zeptovolt);
+            test(p::yoctowatt, v::yoctovolt);
+
+            fn test<A: p::Conversion<V>, B: v::Conversion<V>>(_a: A, _b: B) {
+                // Skip prefixes that are out of range for the storage type.
+                if <A as ::Conversion<V>>::try_conversion().is_some()
+                    && <B as ::Conversion<V>>::try_conversion().is_some()
+                {
+                    assert_eq!(ElectricPotential::new::<B>(V::one()),
+                        Power::new::<A>(V::one()) / ElectricCurrent::new::<i::ampere>(V::one()));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Electrical conductance (base unit siemens, kg<sup>-1</sup> · m<sup>-2</sup> · s<sup>3</sup> ·
+//! A<sup>2</sup>).
+
+quantity! {
+    /// Electrical conductance (base unit siemens, kg<sup>-1</sup> · m<sup>-2</sup> · s<sup>3</sup>
+    /// · A<sup>2</sup>).
+    quantity: ElectricalConductance; "electrical conductance";
+    /// Electrical conductance dimension, kg<sup>-1</sup> · m<sup>-2</sup> · s<sup>3</sup> ·
+    /// A<sup>2</sup>.
+    dimension: ISQ<
+        N2,     // length
+        N1,     // mass
+        P3,     // time
+        P2,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    units {
+        @yottasiemens: prefix!(yotta); "YS", "yottasiemens", "yottasiemens";
+        @zettasiemens: prefix!(zetta); "ZS", "zettasiemens", "zettasiemens";
+        @exasiemens: prefix!(exa); "ES", "exasiemens", "exasiemens";
+        @petasiemens: prefix!(peta); "PS", "petasiemens", "petasiemens";
+        @terasiemens: prefix!(tera); "TS", "terasiemens", "terasiemens";
+        @gigasiemens: prefix!(giga); "GS", "gigasiemens", "gigasiemens";
+        @megasiemens: prefix!(mega); "MS", "megasiemens", "megasiemens";
+        @kilosiemens: prefix!(kilo); "kS", "kilosiemens", "kilosiemens";
+        @hectosiemens: prefix!(hecto); "hS", "hectosiemens", "hectosiemens";
+        @decasiemens: prefix!(deca); "daS", "decasiemens", "decasiemens";
+        /// Derived unit of electrical conductance.
+        @siemens: prefix!(none); "S", "siemens", "siemens";
+        @decisiemens: prefix!(deci); "dS", "decisiemens", "decisiemens";
+        @centisiemens: prefix!(centi); "cS", "centisiemens", "centisiemens";
+        @millisiemens: prefix!(milli); "mS", "millisiemens", "millisiemens";
+        @microsiemens: prefix!(micro); "µS", "microsiemens", "microsiemens";
+        @nanosiemens: prefix!(nano); "nS", "nanosiemens", "nanosiemens";
+        @picosiemens: prefix!(pico); "pS", "picosiemens", "picosiemens";
+        @femtosiemens: prefix!(femto); "fS", "femtosiemens", "femtosiemens";
+        @attosiemens: prefix!(atto); "aS", "attosiemens", "attosiemens";
+        @zeptosiemens: prefix!(zepto); "zS", "zeptosiemens", "zeptosiemens";
+        @yoctosiemens: prefix!(yocto); "yS", "yoctosiemens", "yoctosiemens";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        use num::One;
+        use si::quantities::*;
+        use si::electric_current as i;
+        use si::electric_potential as v;
+        use si::electrical_conductance as g;
+
+        #[test]
+        fn check_dimension() {
+            let _: ElectricalConductance<V> = ElectricCurrent::new::<i::ampere>(V::one())
+                / ElectricPotential::new::<v::volt>(V::one());
+        }
+
+        #[test]
+        fn check_units() {
+            test(i::yottaampere, g::yottasiemens);
+            test(i::zettaampere, g::zettasiemens);
+            test(i::exaampere, g::exasiemens);
+            test(i::petaampere, g::petasiemens);
+            test(i::teraampere, g::terasiemens);
+            test(i::gigaampere, g::gigasiemens);
+            test(i::megaampere, g::megasiemens);
+            test(i::kiloampere, g::kilosiemens);
+            test(i::hectoampere, g::hectosiemens);
+            test(i::decaampere, g::decasiemens);
+            test(i::ampere, g::siemens);
+            test(i::deciampere, g::decisiemens);
+            test(i::centiampere, g::centisiemens);
+            test(i::milliampere, g::millisiemens);
+            test(i::microampere, g::microsiemens);
+            test(i::nanoampere, g::nanosiemens);
+            test(i::picoampere, g::picosiemens);
+            test(i::femtoampere, g::femtosiemens);
+            test(i::attoampere, g::attosiemens);
+            test(i::zeptoampere, g::zeptosiemens);
+            test(i::yoctoampere, g::yoctosiemens);
+
+            fn test<A: i::Conversion<V>, B: g::Conversion<V>>(_a: A, _b: B) {
+                // Skip prefixes that are out of range for the storage type.
+                if <A as ::Conversion<V>>::try_conversion().is_some()
+                    && <B as ::Conversion<V>>::try_conversion().is_some()
+                {
+                    assert_eq!(ElectricalConductance::new::<B>(V::one()),
+                        ElectricCurrent::new::<A>(V::one())
+                            / ElectricPotential::new::<v::volt>(V::one()));
+                }
+            }
+        }
+    }
+}
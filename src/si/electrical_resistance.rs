@@ -0,0 +1,92 @@
+//! Electrical resistance (base unit ohm, kg · m<sup>2</sup> · s<sup>-3</sup> · A<sup>-2</sup>).
+
+quantity! {
+    /// Electrical resistance (base unit ohm, kg · m<sup>2</sup> · s<sup>-3</sup> · A<sup>-2</sup>).
+    quantity: ElectricalResistance; "electrical resistance";
+    /// Electrical resistance dimension, kg · m<sup>2</sup> · s<sup>-3</sup> · A<sup>-2</sup>.
+    dimension: ISQ<
+        P2,     // length
+        P1,     // mass
+        N3,     // time
+        N2,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    units {
+        @yottaohm: prefix!(yotta); "YΩ", "yottaohm", "yottaohms";
+        @zettaohm: prefix!(zetta); "ZΩ", "zettaohm", "zettaohms";
+        @exaohm: prefix!(exa); "EΩ", "exaohm", "exaohms";
+        @petaohm: prefix!(peta); "PΩ", "petaohm", "petaohms";
+        @teraohm: prefix!(tera); "TΩ", "teraohm", "teraohms";
+        @gigaohm: prefix!(giga); "GΩ", "gigaohm", "gigaohms";
+        @megaohm: prefix!(mega); "MΩ", "megaohm", "megaohms";
+        @kiloohm: prefix!(kilo); "kΩ", "kiloohm", "kiloohms";
+        @hectoohm: prefix!(hecto); "hΩ", "hectoohm", "hectoohms";
+        @decaohm: prefix!(deca); "daΩ", "decaohm", "decaohms";
+        /// Derived unit of electrical resistance.
+        @ohm: prefix!(none); "Ω", "ohm", "ohms";
+        @deciohm: prefix!(deci); "dΩ", "deciohm", "deciohms";
+        @centiohm: prefix!(centi); "cΩ", "centiohm", "centiohms";
+        @milliohm: prefix!(milli); "mΩ", "milliohm", "milliohms";
+        @microohm: prefix!(micro); "µΩ", "microohm", "microohms";
+        @nanoohm: prefix!(nano); "nΩ", "nanoohm", "nanoohms";
+        @picoohm: prefix!(pico); "pΩ", "picoohm", "picoohms";
+        @femtoohm: prefix!(femto); "fΩ", "femtoohm", "femtoohms";
+        @attoohm: prefix!(atto); "aΩ", "attoohm", "attoohms";
+        @zeptoohm: prefix!(zepto); "zΩ", "zeptoohm", "zeptoohms";
+        @yoctoohm: prefix!(yocto); "yΩ", "yoctoohm", "yoctoohms";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        use num::One;
+        use si::quantities::*;
+        use si::electric_current as i;
+        use si::electric_potential as v;
+        use si::electrical_resistance as r;
+
+        #[test]
+        fn check_dimension() {
+            let _: ElectricalResistance<V> = ElectricPotential::new::<v::volt>(V::one())
+                / ElectricCurrent::new::<i::ampere>(V::one());
+        }
+
+        #[test]
+        fn check_units() {
+            test(v::yottavolt, r::yottaohm);
+            test(v::zettavolt, r::zettaohm);
+            test(v::exavolt, r::exaohm);
+            test(v::petavolt, r::petaohm);
+            test(v::teravolt, r::teraohm);
+            test(v::gigavolt, r::gigaohm);
+            test(v::megavolt, r::megaohm);
+            test(v::kilovolt, r::kiloohm);
+            test(v::hectovolt, r::hectoohm);
+            test(v::decavolt, r::decaohm);
+            test(v::volt, r::ohm);
+            test(v::decivolt, r::deciohm);
+            test(v::centivolt, r::centiohm);
+            test(v::millivolt, r::milliohm);
+            test(v::microvolt, r::microohm);
+            test(v::nanovolt, r::nanoohm);
+            test(v::picovolt, r::picoohm);
+            test(v::femtovolt, r::femtoohm);
+            test(v::attovolt, r::attoohm);
+            test(v::zeptovolt, r::zeptoohm);
+            test(v::yoctovolt, r::yoctoohm);
+
+            fn test<A: v::Conversion<V>, B: r::Conversion<V>>(_a: A, _b: B) {
+                // Skip prefixes that are out of range for the storage type.
+                if <A as ::Conversion<V>>::try_conversion().is_some()
+                    && <B as ::Conversion<V>>::try_conversion().is_some()
+                {
+                    assert_eq!(ElectricalResistance::new::<B>(V::one()),
+                        ElectricPotential::new::<A>(V::one())
+                            / ElectricCurrent::new::<i::ampere>(V::one()));
+                }
+            }
+        }
+    }
+}
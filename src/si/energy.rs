@@ -0,0 +1,129 @@
+//! Energy (base unit joule, kg · m<sup>2</sup> · s<sup>-2</sup>).
+
+quantity! {
+    /// Energy (base unit joule, kg · m<sup>2</sup> · s<sup>-2</sup>).
+    quantity: Energy; "energy";
+    /// Energy dimension, kg · m<sup>2</sup> · s<sup>-2</sup>.
+    dimension: ISQ<
+        P2,     // length
+        P1,     // mass
+        N2,     // time
+        Z0,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    units {
+        @yottajoule: prefix!(yotta); "YJ", "yottajoule", "yottajoules";
+        @zettajoule: prefix!(zetta); "ZJ", "zettajoule", "zettajoules";
+        @exajoule: prefix!(exa); "EJ", "exajoule", "exajoules";
+        @petajoule: prefix!(peta); "PJ", "petajoule", "petajoules";
+        @terajoule: prefix!(tera); "TJ", "terajoule", "terajoules";
+        @gigajoule: prefix!(giga); "GJ", "gigajoule", "gigajoules";
+        @megajoule: prefix!(mega); "MJ", "megajoule", "megajoules";
+        @kilojoule: prefix!(kilo); "kJ", "kilojoule", "kilojoules";
+        @hectojoule: prefix!(hecto); "hJ", "hectojoule", "hectojoules";
+        @decajoule: prefix!(deca); "daJ", "decajoule", "decajoules";
+        /// Derived unit of energy.
+        @joule: prefix!(none); "J", "joule", "joules";
+        @decijoule: prefix!(deci); "dJ", "decijoule", "decijoules";
+        @centijoule: prefix!(centi); "cJ", "centijoule", "centijoules";
+        @millijoule: prefix!(milli); "mJ", "millijoule", "millijoules";
+        @microjoule: prefix!(micro); "µJ", "microjoule", "microjoules";
+        @nanojoule: prefix!(nano); "nJ", "nanojoule", "nanojoules";
+        @picojoule: prefix!(pico); "pJ", "picojoule", "picojoules";
+        @femtojoule: prefix!(femto); "fJ", "femtojoule", "femtojoules";
+        @attojoule: prefix!(atto); "aJ", "attojoule", "attojoules";
+        @zeptojoule: prefix!(zepto); "zJ", "zeptojoule", "zeptojoules";
+        @yoctojoule: prefix!(yocto); "yJ", "yoctojoule", "yoctojoules";
+
+        @british_thermal_unit: 1.055_056_E3; "Btu", "British thermal unit",
+            "British thermal units";
+        @calorie: 4.184_E0; "cal", "calorie", "calories";
+        @electronvolt: 1.602_176_634_E-19; "eV", "electronvolt", "electronvolts";
+        @erg: 1.0_E-7; "erg", "erg", "ergs";
+        @foot_pound: 1.355_818_E0; "ft · lbf", "foot pound-force", "foot pounds-force";
+        @kilocalorie: 4.184_E3; "kcal", "kilocalorie", "kilocalories";
+        @kilowatt_hour: 3.6_E6; "kW · h", "kilowatt hour", "kilowatt hours";
+        @watt_hour: 3.6_E3; "W · h", "watt hour", "watt hours";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        use num::One;
+        use si::quantities::*;
+        use si::energy as e;
+        use si::force as f;
+        use si::length as l;
+
+        #[test]
+        fn check_dimension() {
+            let _: Energy<V> = Force::new::<f::newton>(V::one())
+                * Length::new::<l::meter>(V::one());
+        }
+
+        #[test]
+        fn check_units() {
+            test(f::yottanewton, e::yottajoule);
+            test(f::zettanewton, e::zettajoule);
+            test(f::exanewton, e::exajoule);
+            test(f::petanewton, e::petajoule);
+            test(f::teranewton, e::terajoule);
+            test(f::giganewton, e::gigajoule);
+            test(f::meganewton, e::megajoule);
+            test(f::kilonewton, e::kilojoule);
+            test(f::hectonewton, e::hectojoule);
+            test(f::decanewton, e::decajoule);
+            test(f::newton, e::joule);
+            test(f::decinewton, e::decijoule);
+            test(f::centinewton, e::centijoule);
+            test(f::millinewton, e::millijoule);
+            test(f::micronewton, e::microjoule);
+            test(f::nanonewton, e::nanojoule);
+            test(f::piconewton, e::picojoule);
+            test(f::femtonewton, e::femtojoule);
+            test(f::attonewton, e::attojoule);
+            test(f::zeptonewton, e::zeptojoule);
+            test(f::yoctonewton, e::yoctojoule);
+
+            fn test<F: f::Conversion<V>, E: e::Conversion<V>>(_f: F, _e: E) {
+                // Skip prefixes that are out of range for the storage type.
+                if <F as ::Conversion<V>>::try_conversion().is_some()
+                    && <E as ::Conversion<V>>::try_conversion().is_some()
+                {
+                    assert_eq!(Energy::new::<E>(V::one()),
+                        Force::new::<F>(V::one()) * Length::new::<l::meter>(V::one()));
+                }
+            }
+        }
+    }
+
+    mod float {
+        storage_types! {
+            types: Float;
+
+            use si::quantities::*;
+            use si::energy as e;
+            use tests::Test;
+
+            #[test]
+            fn check_units() {
+                Test::assert_approx_eq(&3_600.0,
+                    &Energy::new::<e::kilowatt_hour>(1.0).get(e::kilojoule));
+                Test::assert_approx_eq(&1_000.0,
+                    &Energy::new::<e::kilowatt_hour>(1.0).get(e::watt_hour));
+                Test::assert_approx_eq(&1_000.0,
+                    &Energy::new::<e::kilocalorie>(1.0).get(e::calorie));
+                Test::assert_approx_eq(&4.184, &Energy::new::<e::calorie>(1.0).get(e::joule));
+                Test::assert_approx_eq(&1_055.056,
+                    &Energy::new::<e::british_thermal_unit>(1.0).get(e::joule));
+                Test::assert_approx_eq(&1.0E7, &Energy::new::<e::joule>(1.0).get(e::erg));
+                Test::assert_approx_eq(&1.355_818,
+                    &Energy::new::<e::foot_pound>(1.0).get(e::joule));
+                Test::assert_approx_eq(&1.602_176_634E2,
+                    &Energy::new::<e::electronvolt>(1.0).get(e::zeptojoule));
+            }
+        }
+    }
+}
@@ -35,6 +35,13 @@ quantity! {
         @attonewton: prefix!(atto); "aN", "attonewton", "attonewtons";
         @zeptonewton: prefix!(zepto); "zN", "zeptonewton", "zeptonewtons";
         @yoctonewton: prefix!(yocto); "yN", "yoctonewton", "yoctonewtons";
+
+        @dyne: 1.0_E-5; "dyn", "dyne", "dynes";
+        @kilogram_force: 9.806_65_E0; "kgf", "kilogram-force", "kilograms-force";
+        @kip: 4.448_222_E3; "kip", "kip", "kips";
+        @ounce_force: 2.780_139_E-1; "ozf", "ounce-force", "ounces-force";
+        @pound_force: 4.448_222_E0; "lbf", "pound-force", "pounds-force";
+        @poundal: 1.382_550_E-1; "pdl", "poundal", "poundals";
     }
 }
 
@@ -108,7 +115,6 @@ mod tests {
             test(m::kilogram, l::meter, t::nanosecond, f::exanewton);
             test(m::kilogram, l::meter, t::picosecond, f::yottanewton);
 
-            // TODO #17 Convert to == once PartialEq is implemented.
             fn test<
                 M: m::Conversion<V>,
                 L: l::Conversion<V>,
@@ -118,11 +124,34 @@ mod tests {
                 _m: M,
                 _l: L,
                 _t: T,
-                f: F
+                _f: F
             ) {
-                Test::assert_approx_eq(&V::one(), &((Mass::new::<M>(V::one())
+                Test::assert_approx_eq(&Force::new::<F>(V::one()), &((Mass::new::<M>(V::one())
                         * Length::new::<L>(V::one()))
-                        / (Time::new::<T>(V::one()) * Time::new::<T>(V::one()))).get(f));
+                        / (Time::new::<T>(V::one()) * Time::new::<T>(V::one()))));
+            }
+        }
+    }
+
+    mod float {
+        storage_types! {
+            types: Float;
+
+            use si::quantities::*;
+            use si::force as f;
+            use tests::Test;
+
+            #[test]
+            fn check_units() {
+                Test::assert_approx_eq(&10.0, &Force::new::<f::dyne>(1.0).get(f::micronewton));
+                Test::assert_approx_eq(&9.806_65,
+                    &Force::new::<f::kilogram_force>(1.0).get(f::newton));
+                Test::assert_approx_eq(&4.448_222, &Force::new::<f::kip>(1.0).get(f::kilonewton));
+                Test::assert_approx_eq(&0.278_013_9,
+                    &Force::new::<f::ounce_force>(1.0).get(f::newton));
+                Test::assert_approx_eq(&4.448_222,
+                    &Force::new::<f::pound_force>(1.0).get(f::newton));
+                Test::assert_approx_eq(&0.138_255, &Force::new::<f::poundal>(1.0).get(f::newton));
             }
         }
     }
@@ -77,12 +77,11 @@ mod tests {
             test(t::zeptosecond, f::zettahertz);
             test(t::yoctosecond, f::yottahertz);
 
-            // TODO #17 Convert to == once PartialEq is implemented.
-            fn test<T: t::Conversion<V>, F: f::Conversion<V>>(t: T, f: F) {
-                Test::assert_approx_eq(&(V::one() / Time::new::<T>(V::one())).get(f),
-                    &Frequency::new::<F>(V::one()).get(f));
-                Test::assert_approx_eq(&Time::new::<T>(V::one()).get(t),
-                    &(V::one() / Frequency::new::<F>(V::one())).get(t));
+            fn test<T: t::Conversion<V>, F: f::Conversion<V>>(_t: T, _f: F) {
+                Test::assert_approx_eq(&Frequency::new::<F>(V::one()),
+                    &(V::one() / Time::new::<T>(V::one())));
+                Test::assert_approx_eq(&Time::new::<T>(V::one()),
+                    &(V::one() / Frequency::new::<F>(V::one())));
             }
         }
     }
@@ -0,0 +1,92 @@
+//! Inductance (base unit henry, kg · m<sup>2</sup> · s<sup>-2</sup> · A<sup>-2</sup>).
+
+quantity! {
+    /// Inductance (base unit henry, kg · m<sup>2</sup> · s<sup>-2</sup> · A<sup>-2</sup>).
+    quantity: Inductance; "inductance";
+    /// Inductance dimension, kg · m<sup>2</sup> · s<sup>-2</sup> · A<sup>-2</sup>.
+    dimension: ISQ<
+        P2,     // length
+        P1,     // mass
+        N2,     // time
+        N2,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    units {
+        @yottahenry: prefix!(yotta); "YH", "yottahenry", "yottahenries";
+        @zettahenry: prefix!(zetta); "ZH", "zettahenry", "zettahenries";
+        @exahenry: prefix!(exa); "EH", "exahenry", "exahenries";
+        @petahenry: prefix!(peta); "PH", "petahenry", "petahenries";
+        @terahenry: prefix!(tera); "TH", "terahenry", "terahenries";
+        @gigahenry: prefix!(giga); "GH", "gigahenry", "gigahenries";
+        @megahenry: prefix!(mega); "MH", "megahenry", "megahenries";
+        @kilohenry: prefix!(kilo); "kH", "kilohenry", "kilohenries";
+        @hectohenry: prefix!(hecto); "hH", "hectohenry", "hectohenries";
+        @decahenry: prefix!(deca); "daH", "decahenry", "decahenries";
+        /// Derived unit of inductance.
+        @henry: prefix!(none); "H", "henry", "henries";
+        @decihenry: prefix!(deci); "dH", "decihenry", "decihenries";
+        @centihenry: prefix!(centi); "cH", "centihenry", "centihenries";
+        @millihenry: prefix!(milli); "mH", "millihenry", "millihenries";
+        @microhenry: prefix!(micro); "µH", "microhenry", "microhenries";
+        @nanohenry: prefix!(nano); "nH", "nanohenry", "nanohenries";
+        @picohenry: prefix!(pico); "pH", "picohenry", "picohenries";
+        @femtohenry: prefix!(femto); "fH", "femtohenry", "femtohenries";
+        @attohenry: prefix!(atto); "aH", "attohenry", "attohenries";
+        @zeptohenry: prefix!(zepto); "zH", "zeptohenry", "zeptohenries";
+        @yoctohenry: prefix!(yocto); "yH", "yoctohenry", "yoctohenries";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        use num::One;
+        use si::quantities::*;
+        use si::electric_current as i;
+        use si::inductance as h;
+        use si::magnetic_flux as f;
+
+        #[test]
+        fn check_dimension() {
+            let _: Inductance<V> = MagneticFlux::new::<f::weber>(V::one())
+                / ElectricCurrent::new::<i::ampere>(V::one());
+        }
+
+        #[test]
+        fn check_units() {
+            test(f::yottaweber, h::yottahenry);
+            test(f::zettaweber, h::zettahenry);
+            test(f::exaweber, h::exahenry);
+            test(f::petaweber, h::petahenry);
+            test(f::teraweber, h::terahenry);
+            test(f::gigaweber, h::gigahenry);
+            test(f::megaweber, h::megahenry);
+            test(f::kiloweber, h::kilohenry);
+            test(f::hectoweber, h::hectohenry);
+            test(f::decaweber, h::decahenry);
+            test(f::weber, h::henry);
+            test(f::deciweber, h::decihenry);
+            test(f::centiweber, h::centihenry);
+            test(f::milliweber, h::millihenry);
+            test(f::microweber, h::microhenry);
+            test(f::nanoweber, h::nanohenry);
+            test(f::picoweber, h::picohenry);
+            test(f::femtoweber, h::femtohenry);
+            test(f::attoweber, h::attohenry);
+            test(f::zeptoweber, h::zeptohenry);
+            test(f::yoctoweber, h::yoctohenry);
+
+            fn test<A: f::Conversion<V>, B: h::Conversion<V>>(_a: A, _b: B) {
+                // Skip prefixes that are out of range for the storage type.
+                if <A as ::Conversion<V>>::try_conversion().is_some()
+                    && <B as ::Conversion<V>>::try_conversion().is_some()
+                {
+                    assert_eq!(Inductance::new::<B>(V::one()),
+                        MagneticFlux::new::<A>(V::one())
+                            / ElectricCurrent::new::<i::ampere>(V::one()));
+                }
+            }
+        }
+    }
+}
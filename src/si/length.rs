@@ -42,39 +42,25 @@ quantity! {
         @chain: 2.011_684_E1; "ch", "chain", "chains";
         @fathom: 1.828_804_E0; "fathom", "fathom", "fathoms";
         @fermi: 1.0_E-15; "fm", "fermi", "fermis";
-        @foot: 3.048_E-1; "ft", "foot", "feet";
+        @foot: 3.048_E-1 => 3_048 / 10_000; "ft", "foot", "feet";
         @foot_survey: 3.048_006_E-1; "ft", "foot (U.S. survey)", "feet (U.S. survey)";
-        @inch: 2.54_E-2; "in", "inch", "inches";
+        @inch: 2.54_E-2 => 254 / 10_000; "in", "inch", "inches";
         @light_year: 9.460_73_E15; "l. y.", "light year", "light years";
-        @microinch: 2.54_E-8; "μin", "microinch", "microinches";
+        @microinch: 2.54_E-8 => 254 / 10_000_000_000; "μin", "microinch", "microinches";
         @micron: 1.0_E-6; "μ", "micron", "microns";
-        @mil: 2.54_E-5; "0.001 in", "mil", "mils";
-        @mile: 1.609_344_E3; "mi", "mile", "miles";
+        @mil: 2.54_E-5 => 254 / 10_000_000; "0.001 in", "mil", "mils";
+        @mile: 1.609_344_E3 => 1_609_344 / 1_000; "mi", "mile", "miles";
         @mile_survey: 1.609_347_E3; "mi", "mile (U.S. survey)", "miles (U.S. survey)";
         @nautical_mile: 1.852_E3; "M", "nautical mile", "nautical miles";
         @parsec: 3.085_678_E16; "pc", "parsec", "parsecs";
-        @pica_computer: 4.233_333_333_333_333_E-3; "1/6 in", "pica (computer)", "picas (computer)";
+        @pica_computer: 4.233_333_333_333_333_E-3 => 254 / 60_000; "1/6 in", "pica (computer)",
+            "picas (computer)";
         @pica_printers: 4.217_518_E-3; "1/6 in", "pica (printer's)", "picas (printer's)";
-        @point_computer: 3.527_778_E-4; "1/72 in", "point (computer)", "points (computer)";
+        @point_computer: 3.527_778_E-4 => 254 / 720_000; "1/72 in", "point (computer)",
+            "points (computer)";
         @point_printers: 3.514_598_E-4; "1/72 in", "point (printer's)", "points (printer's)";
         @rod: 5.029_21_E0; "rd", "rod", "rods";
-        @yard: 9.144_E-1; "yd", "yard", "yards";
-    }
-}
-
-impl<U, V> Length<U, V>
-where
-    U: super::Units<V> + ?Sized,
-    V: ::num::Float + ::Conversion<V>,
-{
-    /// Calculates the length of the hypotenuse of a right-angle triangle given the legs.
-    #[inline(always)]
-    pub fn hypot(self, other: Self) -> Self {
-        Length {
-            dimension: ::lib::marker::PhantomData,
-            units: ::lib::marker::PhantomData,
-            value: self.value.hypot(other.value)
-        }
+        @yard: 9.144_E-1 => 9_144 / 10_000; "yd", "yard", "yards";
     }
 }
 
@@ -94,5 +80,91 @@ mod tests {
                     &Length::new::<meter>(l).hypot(Length::new::<meter>(r)).get(meter))
             }
         }
+
+        #[test]
+        fn mixed() {
+            use si::length::{foot, inch, yard};
+
+            let m = Length::new::<inch>(12.0).into_mixed((foot, inch));
+
+            Test::assert_eq(&1.0, &m.values.0);
+            Test::assert_eq(&0.0, &m.values.1);
+            assert_eq!("1 ft 0 in", format!("{}", m));
+            assert_eq!("6 ft 0 in",
+                format!("{:.0}", Length::new::<inch>(71.9).into_mixed((foot, inch))));
+            assert_eq!("-5 ft 11.5 in",
+                format!("{:.1}", Length::new::<inch>(-71.5).into_mixed((foot, inch))));
+            assert_eq!("2 yd 0 ft 0 in",
+                format!("{:.0}", Length::new::<inch>(71.9).into_mixed((yard, foot, inch))));
+            assert_eq!("1 yd 2 ft 11 in",
+                format!("{:.0}", Length::new::<inch>(71.0).into_mixed((yard, foot, inch))));
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde_survey_units() {
+            use si::length::{foot, foot_survey, inch, mile_survey, Tagged};
+            use si::SI;
+
+            let l = Length::new::<foot_survey>(3.0);
+            let s = ::serde_json::to_string(&Tagged::new(l, foot_survey)).unwrap();
+            let t: Tagged<SI<V>, V> = ::serde_json::from_str(&s).unwrap();
+            let m: Tagged<SI<V>, V> =
+                ::serde_json::from_str(r#"{"value": 1.0, "unit": "miles (U.S. survey)"}"#).unwrap();
+
+            assert!(s.contains(r#""unit":"foot (U.S. survey)""#));
+            assert!(::serde_json::to_string(&Tagged::new(l, inch)).unwrap()
+                .contains(r#""unit":"in""#));
+            assert_eq!(l, t.into_inner());
+            assert_eq!(Length::new::<mile_survey>(1.0), m.into_inner());
+            assert_eq!(Length::new::<foot>(1.0),
+                ::serde_json::from_str::<Tagged<SI<V>, V>>(r#"{"value": 1.0, "unit": "ft"}"#)
+                    .unwrap().into_inner());
+        }
+    }
+
+    mod exact {
+        storage_types! {
+            // Exact conversion factors overflow `i8`, `u8`, `i16`, and `u16`.
+            types: i32, i64, isize, u32, u64, usize, BigInt, BigUint, Ratio;
+
+            use num::FromPrimitive;
+            use si::quantities::*;
+            use si::length as l;
+            use tests::Test;
+
+            #[test]
+            fn check_units() {
+                test(l::foot, 3_048, 10_000);
+                test(l::inch, 254, 10_000);
+                test(l::mile, 1_609_344, 1_000);
+                test(l::pica_computer, 254, 60_000);
+                test(l::point_computer, 254, 720_000);
+                test(l::yard, 9_144, 10_000);
+
+                fn test<U: l::Conversion<V>>(u: U, m: u64, v: u64) {
+                    Test::assert_eq(&V::from_u64(v).unwrap(),
+                        &Length::new::<l::meter>(V::from_u64(m).unwrap()).get(u));
+                }
+            }
+        }
+    }
+
+    mod out_of_range {
+        storage_types! {
+            types: i8, u8;
+
+            use si::quantities::*;
+            use si::length as l;
+            use {Conversion, ConversionError};
+
+            #[test]
+            fn try_conversion() {
+                assert!(<l::meter as Conversion<V>>::try_conversion().is_some());
+                assert!(<l::inch as Conversion<V>>::try_conversion().is_none());
+                assert!(<l::yottameter as Conversion<V>>::try_conversion().is_none());
+                assert_eq!(Err(ConversionError::Overflow), Length::<V>::try_new::<l::inch>(1));
+            }
+        }
     }
 }
@@ -0,0 +1,495 @@
+//! Logarithmic [levels](https://www.iso.org/standard/64974.html) of quantities (decibel, bel,
+//! neper).
+//!
+//! A [`Level`](struct.Level.html) is the logarithm of the ratio of a quantity to a reference value
+//! of the same quantity. The reference (e.g. 1 mW for dBm) is given by a type implementing
+//! [`Reference`](trait.Reference.html). Power quantities (e.g. power, energy) use
+//! 10 · log<sub>10</sub> of the ratio and root-power (field) quantities (e.g. voltage, sound
+//! pressure) use 20 · log<sub>10</sub> of the ratio so that a level in decibels is the same for a
+//! power and for the corresponding root-power quantity.
+//!
+//! Adding a relative level (a gain, e.g. [`Level<PowerRatio, V>`](enum.PowerRatio.html)) to a
+//! level multiplies the linear value of the level by the gain's ratio. Subtracting two levels with
+//! the same reference gives the relative level between them. Levels with floating point storage
+//! types are supported.
+//!
+#![cfg_attr(all(feature = "si", feature = "f64"), doc = " ```rust")]
+#![cfg_attr(not(all(feature = "si", feature = "f64")), doc = " ```rust,ignore")]
+//! # use uom::si::f64::*;
+//! # use uom::si::level::{decibel, neper, Dbm, Level, PowerRatio};
+//! # use uom::si::power::{milliwatt, watt};
+//! let l: Level<Dbm, f64> = Level::from_quantity(Power::new::<watt>(1.0));
+//! let g: Level<PowerRatio, f64> = Level::new::<decibel>(-10.0);
+//! let p: Power = (l + g).into_quantity();
+//!
+//! assert!((l.get(decibel) - 30.0).abs() < 1.0E-9);
+//! assert!((p.get(milliwatt) - 100.0).abs() < 1.0E-9);
+//! assert!((Level::<Dbm, f64>::new::<neper>(1.0).get(decibel) - 8.685_889_638).abs() < 1.0E-9);
+//! assert_eq!("30 dBm", format!("{:.0}", l));
+//! ```
+
+use lib::fmt;
+use lib::marker::PhantomData;
+use lib::ops::{Add, Neg, Sub};
+use num::{Float, NumCast};
+use si::{change_base, Dimension, Quantity, Units, SI};
+use Conversion;
+
+/// Kind of quantity a level is computed for. Determines the factor applied to the logarithm of the
+/// ratio.
+pub trait LevelKind: 'static {
+    /// Reference of relative levels (gains) of quantities of this kind.
+    type Ratio;
+
+    /// Factor applied to log<sub>10</sub> of the ratio to get the level in decibels.
+    fn factor() -> f64;
+}
+
+/// Power quantity (e.g. power, energy, intensity). The level in decibels is 10 · log<sub>10</sub>
+/// of the ratio.
+#[derive(Clone, Copy, Debug)]
+pub enum PowerQuantity {}
+
+/// Root-power (field) quantity (e.g. voltage, current, sound pressure). The level in decibels is
+/// 20 · log<sub>10</sub> of the ratio.
+#[derive(Clone, Copy, Debug)]
+pub enum RootPowerQuantity {}
+
+impl LevelKind for PowerQuantity {
+    type Ratio = PowerRatio;
+
+    #[inline(always)]
+    fn factor() -> f64 {
+        10.0
+    }
+}
+
+impl LevelKind for RootPowerQuantity {
+    type Ratio = FieldRatio;
+
+    #[inline(always)]
+    fn factor() -> f64 {
+        20.0
+    }
+}
+
+/// Reference value of a level. Implemented by marker types for common references (e.g.
+/// [`Dbm`](enum.Dbm.html)) for floating point storage types. Additional references can be defined
+/// by implementing this trait.
+pub trait Reference<V>: 'static {
+    /// Dimension of the quantity.
+    type Dimension: Dimension + ?Sized;
+
+    /// Kind of the quantity, [`PowerQuantity`](enum.PowerQuantity.html) or
+    /// [`RootPowerQuantity`](enum.RootPowerQuantity.html).
+    type Kind: LevelKind;
+
+    /// Reference value in the base units of the SI.
+    fn reference() -> V;
+
+    /// Abbreviation of the level's unit (e.g. `dBm`).
+    fn abbreviation() -> &'static str;
+}
+
+/// Trait to identify measurement units of a level.
+pub trait Unit: Copy {
+    /// Level in decibels of one unit.
+    fn decibels() -> f64;
+}
+
+/// Decibel, one tenth of a bel.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Hash)]
+pub struct decibel;
+
+/// Bel, the level of a power quantity with a ratio of 10.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Hash)]
+pub struct bel;
+
+/// Neper, the level of a root-power quantity with a ratio of e. 1 Np = 20 / ln(10) dB.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, Hash)]
+pub struct neper;
+
+impl Unit for decibel {
+    #[inline(always)]
+    fn decibels() -> f64 {
+        1.0
+    }
+}
+
+impl Unit for bel {
+    #[inline(always)]
+    fn decibels() -> f64 {
+        10.0
+    }
+}
+
+impl Unit for neper {
+    #[inline(always)]
+    fn decibels() -> f64 {
+        20.0 / ::lib::f64::consts::LN_10
+    }
+}
+
+/// Logarithmic level of a quantity relative to the reference `R`. The level is stored in
+/// decibels.
+pub struct Level<R, V> {
+    /// Level in decibels.
+    pub value: V,
+    reference: PhantomData<R>,
+}
+
+impl<R, V> Level<R, V>
+where
+    R: Reference<V>,
+    V: Float + Conversion<V>,
+    SI<V>: Units<V>,
+{
+    /// Create a new level from the given value and measurement unit.
+    #[inline(always)]
+    pub fn new<N>(v: V) -> Self
+    where
+        N: Unit,
+    {
+        Level {
+            value: v * cast(N::decibels()),
+            reference: PhantomData,
+        }
+    }
+
+    /// Retrieve the value of the level in the given measurement unit.
+    #[inline(always)]
+    pub fn get<N>(&self, _unit: N) -> V
+    where
+        N: Unit,
+    {
+        self.value / cast(N::decibels())
+    }
+
+    /// Create a level from the given linear quantity in any base units. Zero gives a level of
+    /// negative infinity and negative quantities give NaN.
+    #[inline(always)]
+    pub fn from_quantity<U>(q: Quantity<R::Dimension, U, V>) -> Self
+    where
+        U: Units<V> + ?Sized,
+    {
+        let value = change_base::<R::Dimension, SI<V>, U, V>(&q.value);
+
+        Level {
+            value: cast::<V>(<R::Kind as LevelKind>::factor()) * (value / R::reference()).log10(),
+            reference: PhantomData,
+        }
+    }
+
+    /// Convert the level into the linear quantity in the given base units.
+    #[inline(always)]
+    pub fn into_quantity<U>(self) -> Quantity<R::Dimension, U, V>
+    where
+        U: Units<V> + ?Sized,
+    {
+        let value = R::reference()
+            * cast::<V>(10.0).powf(self.value / cast(<R::Kind as LevelKind>::factor()));
+
+        Quantity {
+            dimension: PhantomData,
+            units: PhantomData,
+            value: change_base::<R::Dimension, U, SI<V>, V>(&value),
+        }
+    }
+}
+
+impl<R, U, V> From<Quantity<R::Dimension, U, V>> for Level<R, V>
+where
+    R: Reference<V>,
+    U: Units<V> + ?Sized,
+    V: Float + Conversion<V>,
+    SI<V>: Units<V>,
+{
+    #[inline(always)]
+    fn from(q: Quantity<R::Dimension, U, V>) -> Self {
+        Self::from_quantity(q)
+    }
+}
+
+impl<R, V> Clone for Level<R, V>
+where
+    V: Clone,
+{
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Level {
+            value: self.value.clone(),
+            reference: PhantomData,
+        }
+    }
+}
+
+impl<R, V> Copy for Level<R, V>
+where
+    V: Copy,
+{
+}
+
+impl<R, V> fmt::Debug for Level<R, V>
+where
+    R: Reference<V>,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)?;
+        write!(f, " {}", R::abbreviation())
+    }
+}
+
+/// Displays the level in decibels followed by the abbreviation of the reference (e.g. `30 dBm`).
+/// Formatting flags are applied to the value.
+impl<R, V> fmt::Display for Level<R, V>
+where
+    R: Reference<V>,
+    V: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)?;
+        write!(f, " {}", R::abbreviation())
+    }
+}
+
+impl<R, V> PartialEq for Level<R, V>
+where
+    V: PartialEq,
+{
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<R, V> PartialOrd for Level<R, V>
+where
+    V: PartialOrd,
+{
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<::lib::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+/// Adding a relative level multiplies the linear value by the relative level's ratio.
+impl<R, V> Add<Level<<R::Kind as LevelKind>::Ratio, V>> for Level<R, V>
+where
+    R: Reference<V>,
+    V: Add<Output = V>,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Level<<R::Kind as LevelKind>::Ratio, V>) -> Self::Output {
+        Level {
+            value: self.value + rhs.value,
+            reference: PhantomData,
+        }
+    }
+}
+
+/// The difference of two levels is the relative level between them.
+impl<R, V> Sub for Level<R, V>
+where
+    R: Reference<V>,
+    V: Sub<Output = V>,
+{
+    type Output = Level<<R::Kind as LevelKind>::Ratio, V>;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Level {
+            value: self.value - rhs.value,
+            reference: PhantomData,
+        }
+    }
+}
+
+impl<R, V> Neg for Level<R, V>
+where
+    V: Neg<Output = V>,
+{
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self::Output {
+        Level {
+            value: -self.value,
+            reference: PhantomData,
+        }
+    }
+}
+
+/// Reference of relative levels of power quantities (gains), a ratio of 1 (dB).
+#[derive(Clone, Copy, Debug)]
+pub enum PowerRatio {}
+
+/// Reference of relative levels of root-power quantities (gains), a ratio of 1 (dB).
+#[derive(Clone, Copy, Debug)]
+pub enum FieldRatio {}
+
+/// Reference of power levels, 1 mW (dBm).
+#[derive(Clone, Copy, Debug)]
+pub enum Dbm {}
+
+/// Reference of power levels, 1 W (dBW).
+#[derive(Clone, Copy, Debug)]
+pub enum Dbw {}
+
+/// Reference of voltage levels, 1 V (dBV).
+#[derive(Clone, Copy, Debug)]
+pub enum Dbv {}
+
+/// Reference of voltage levels, √0.6 V ≈ 0.775 V, the voltage that dissipates 1 mW in 600 Ω
+/// (dBu).
+#[derive(Clone, Copy, Debug)]
+pub enum Dbu {}
+
+/// Reference of sound pressure levels in air, 20 µPa (dB SPL).
+#[derive(Clone, Copy, Debug)]
+pub enum DbSpl {}
+
+/// Cast a factor to the floating point storage type of a level.
+#[inline(always)]
+fn cast<V>(v: f64) -> V
+where
+    V: Float,
+{
+    <V as NumCast>::from(v).expect("factor representable by the storage type")
+}
+
+macro_rules! reference {
+    ($name:ident; $abbreviation:expr; $kind:ident;
+        $module:ident::$quantity:ident::$unit:ident($value:expr)) => {
+        impl ::si::level::Reference<V> for ::si::level::$name {
+            type Dimension = ::si::$module::Dimension;
+            type Kind = ::si::level::$kind;
+
+            #[inline(always)]
+            fn reference() -> V {
+                ::si::$module::$quantity::<::si::SI<V>, V>::new::<::si::$module::$unit>(
+                    ::si::level::cast($value)).value
+            }
+
+            #[inline(always)]
+            fn abbreviation() -> &'static str {
+                $abbreviation
+            }
+        }
+    };
+}
+
+storage_types! {
+    types: Float;
+
+    reference!(PowerRatio; "dB"; PowerQuantity; ratio::Ratio::ratio(1.0));
+    reference!(FieldRatio; "dB"; RootPowerQuantity; ratio::Ratio::ratio(1.0));
+    reference!(Dbm; "dBm"; PowerQuantity; power::Power::milliwatt(1.0));
+    reference!(Dbw; "dBW"; PowerQuantity; power::Power::watt(1.0));
+    reference!(Dbv; "dBV"; RootPowerQuantity; electric_potential::ElectricPotential::volt(1.0));
+    reference!(Dbu; "dBu"; RootPowerQuantity;
+        electric_potential::ElectricPotential::volt(0.774_596_669_241_483_4));
+    reference!(DbSpl; "dB SPL"; RootPowerQuantity; pressure::Pressure::micropascal(20.0));
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        types: Float;
+
+        use num::Float;
+        use si::{power, Units, SI};
+        use si::quantities::*;
+        use si::amount_of_substance::mole;
+        use si::electric_current::ampere;
+        use si::electric_potential::volt;
+        use si::length::kilometer;
+        use si::level::*;
+        use si::luminous_intensity::candela;
+        use si::mass::kilogram;
+        use si::power::{milliwatt, watt};
+        use si::pressure::pascal;
+        use si::ratio::ratio;
+        use si::thermodynamic_temperature::kelvin;
+        use si::time::second;
+        use tests::Test;
+
+        #[test]
+        fn power_quantity() {
+            let l: Level<Dbm, V> = Level::from_quantity(Power::new::<watt>(1.0));
+            let w: Level<Dbw, V> = Power::new::<milliwatt>(1.0).into();
+
+            Test::assert_approx_eq(&30.0, &l.get(decibel));
+            Test::assert_approx_eq(&-30.0, &w.get(decibel));
+            Test::assert_approx_eq(&1.0, &l.into_quantity::<SI<V>>().get(watt));
+            Test::assert_approx_eq(&1.0, &w.into_quantity::<SI<V>>().get(milliwatt));
+        }
+
+        #[test]
+        fn base_units() {
+            type Kilometer = Units<V, length = kilometer, mass = kilogram, time = second,
+                electric_current = ampere, thermodynamic_temperature = kelvin,
+                amount_of_substance = mole, luminous_intensity = candela>;
+
+            let p = power::Power::<Kilometer, V>::new::<watt>(1.0);
+            let l: Level<Dbm, V> = Level::from_quantity(p);
+            let q: power::Power<Kilometer, V> = l.into_quantity();
+
+            Test::assert_approx_eq(&30.0, &l.get(decibel));
+            Test::assert_approx_eq(&30.0, &Level::<Dbm, V>::from(p).get(decibel));
+            Test::assert_approx_eq(&1.0E-6, &q.value);
+            Test::assert_approx_eq(&1.0, &q.get(watt));
+        }
+
+        #[test]
+        fn root_power_quantity() {
+            let v: Level<Dbv, V> = Level::from_quantity(ElectricPotential::new::<volt>(10.0));
+            let u: Level<Dbu, V> = Level::new::<decibel>(0.0);
+            let p: Level<DbSpl, V> = Level::new::<decibel>(94.0);
+
+            Test::assert_approx_eq(&20.0, &v.get(decibel));
+            Test::assert_approx_eq(&0.774_596_669_241_483_4,
+                &u.into_quantity::<SI<V>>().get(volt));
+            Test::assert_eq(&1.0, &p.into_quantity::<SI<V>>().get(pascal).round());
+        }
+
+        #[test]
+        fn units() {
+            let l: Level<Dbm, V> = Level::new::<bel>(1.0);
+            let n: Level<FieldRatio, V> = Level::new::<neper>(1.0);
+
+            Test::assert_approx_eq(&10.0, &l.get(decibel));
+            Test::assert_approx_eq(&0.1, &Level::<Dbm, V>::new::<decibel>(1.0).get(bel));
+            Test::assert_approx_eq(&1.0, &n.get(neper));
+            Test::assert_approx_eq(&Float::exp(1.0), &n.into_quantity::<SI<V>>().get(ratio));
+        }
+
+        #[test]
+        fn gain() {
+            let l: Level<Dbm, V> = Level::from_quantity(Power::new::<milliwatt>(2.0));
+            let g: Level<PowerRatio, V> = Level::new::<decibel>(20.0);
+            let v: Level<Dbv, V> = Level::new::<decibel>(0.0);
+            let f: Level<FieldRatio, V> = Level::new::<decibel>(20.0);
+
+            Test::assert_approx_eq(&200.0, &(l + g).into_quantity::<SI<V>>().get(milliwatt));
+            Test::assert_approx_eq(&10.0, &(v + f).into_quantity::<SI<V>>().get(volt));
+            Test::assert_approx_eq(&20.0, &((l + g) - l).get(decibel));
+            Test::assert_approx_eq(&-20.0, &(-g).get(decibel));
+            assert!(l < l + g);
+        }
+
+        #[test]
+        fn fmt() {
+            let l: Level<Dbm, V> = Level::new::<decibel>(30.0);
+            let p: Level<DbSpl, V> = Level::new::<decibel>(94.0);
+
+            assert_eq!("30 dBm", format!("{}", l));
+            assert_eq!("94.0 dB SPL", format!("{:.1}", p));
+        }
+    }
+}
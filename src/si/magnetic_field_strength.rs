@@ -0,0 +1,127 @@
+//! Magnetic field strength (base unit ampere per meter, m<sup>-1</sup> · A).
+
+quantity! {
+    /// Magnetic field strength (base unit ampere per meter, m<sup>-1</sup> · A).
+    quantity: MagneticFieldStrength; "magnetic field strength";
+    /// Magnetic field strength dimension, m<sup>-1</sup> · A.
+    dimension: ISQ<
+        N1,     // length
+        Z0,     // mass
+        Z0,     // time
+        P1,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    units {
+        @yottaampere_per_meter: prefix!(yotta); "YA/m", "yottaampere per meter",
+            "yottaamperes per meter";
+        @zettaampere_per_meter: prefix!(zetta); "ZA/m", "zettaampere per meter",
+            "zettaamperes per meter";
+        @exaampere_per_meter: prefix!(exa); "EA/m", "exaampere per meter", "exaamperes per meter";
+        @petaampere_per_meter: prefix!(peta); "PA/m", "petaampere per meter",
+            "petaamperes per meter";
+        @teraampere_per_meter: prefix!(tera); "TA/m", "teraampere per meter",
+            "teraamperes per meter";
+        @gigaampere_per_meter: prefix!(giga); "GA/m", "gigaampere per meter",
+            "gigaamperes per meter";
+        @megaampere_per_meter: prefix!(mega); "MA/m", "megaampere per meter",
+            "megaamperes per meter";
+        @kiloampere_per_meter: prefix!(kilo); "kA/m", "kiloampere per meter",
+            "kiloamperes per meter";
+        @hectoampere_per_meter: prefix!(hecto); "hA/m", "hectoampere per meter",
+            "hectoamperes per meter";
+        @decaampere_per_meter: prefix!(deca); "daA/m", "decaampere per meter",
+            "decaamperes per meter";
+        @ampere_per_meter: prefix!(none); "A/m", "ampere per meter", "amperes per meter";
+        @deciampere_per_meter: prefix!(deci); "dA/m", "deciampere per meter",
+            "deciamperes per meter";
+        @centiampere_per_meter: prefix!(centi); "cA/m", "centiampere per meter",
+            "centiamperes per meter";
+        @milliampere_per_meter: prefix!(milli); "mA/m", "milliampere per meter",
+            "milliamperes per meter";
+        @microampere_per_meter: prefix!(micro); "µA/m", "microampere per meter",
+            "microamperes per meter";
+        @nanoampere_per_meter: prefix!(nano); "nA/m", "nanoampere per meter",
+            "nanoamperes per meter";
+        @picoampere_per_meter: prefix!(pico); "pA/m", "picoampere per meter",
+            "picoamperes per meter";
+        @femtoampere_per_meter: prefix!(femto); "fA/m", "femtoampere per meter",
+            "femtoamperes per meter";
+        @attoampere_per_meter: prefix!(atto); "aA/m", "attoampere per meter",
+            "attoamperes per meter";
+        @zeptoampere_per_meter: prefix!(zepto); "zA/m", "zeptoampere per meter",
+            "zeptoamperes per meter";
+        @yoctoampere_per_meter: prefix!(yocto); "yA/m", "yoctoampere per meter",
+            "yoctoamperes per meter";
+
+        @oersted: 7.957_747_E1; "Oe", "oersted", "oersteds";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        use num::One;
+        use si::quantities::*;
+        use si::electric_current as i;
+        use si::length as l;
+        use si::magnetic_field_strength as h;
+
+        #[test]
+        fn check_dimension() {
+            let _: MagneticFieldStrength<V> = ElectricCurrent::new::<i::ampere>(V::one())
+                / Length::new::<l::meter>(V::one());
+        }
+
+        #[test]
+        fn check_units() {
+            test(i::yottaampere, h::yottaampere_per_meter);
+            test(i::zettaampere, h::zettaampere_per_meter);
+            test(i::exaampere, h::exaampere_per_meter);
+            test(i::petaampere, h::petaampere_per_meter);
+            test(i::teraampere, h::teraampere_per_meter);
+            test(i::gigaampere, h::gigaampere_per_meter);
+            test(i::megaampere, h::megaampere_per_meter);
+            test(i::kiloampere, h::kiloampere_per_meter);
+            test(i::hectoampere, h::hectoampere_per_meter);
+            test(i::decaampere, h::decaampere_per_meter);
+            test(i::ampere, h::ampere_per_meter);
+            test(i::deciampere, h::deciampere_per_meter);
+            test(i::centiampere, h::centiampere_per_meter);
+            test(i::milliampere, h::milliampere_per_meter);
+            test(i::microampere, h::microampere_per_meter);
+            test(i::nanoampere, h::nanoampere_per_meter);
+            test(i::picoampere, h::picoampere_per_meter);
+            test(i::femtoampere, h::femtoampere_per_meter);
+            test(i::attoampere, h::attoampere_per_meter);
+            test(i::zeptoampere, h::zeptoampere_per_meter);
+            test(i::yoctoampere, h::yoctoampere_per_meter);
+
+            fn test<A: i::Conversion<V>, B: h::Conversion<V>>(_a: A, _b: B) {
+                // Skip prefixes that are out of range for the storage type.
+                if <A as ::Conversion<V>>::try_conversion().is_some()
+                    && <B as ::Conversion<V>>::try_conversion().is_some()
+                {
+                    assert_eq!(MagneticFieldStrength::new::<B>(V::one()),
+                        ElectricCurrent::new::<A>(V::one()) / Length::new::<l::meter>(V::one()));
+                }
+            }
+        }
+    }
+
+    mod float {
+        storage_types! {
+            types: Float;
+
+            use si::quantities::*;
+            use si::magnetic_field_strength as h;
+            use tests::Test;
+
+            #[test]
+            fn check_units() {
+                Test::assert_approx_eq(&79.577_47,
+                    &MagneticFieldStrength::new::<h::oersted>(1.0).get(h::ampere_per_meter));
+            }
+        }
+    }
+}
@@ -36,5 +36,37 @@ quantity! {
         @attogram: prefix!(atto) / prefix!(kilo); "ag", "attogram", "attograms";
         @zeptogram: prefix!(zepto) / prefix!(kilo); "zg", "zeptogram", "zeptograms";
         @yoctogram: prefix!(yocto) / prefix!(kilo); "yg", "yoctogram", "yoctograms";
+
+        @carat: 2.0_E-4; "ct", "carat", "carats";
+        @grain: 6.479_891_E-5; "gr", "grain", "grains";
+        @ounce: 2.834_952_312_5_E-2 => 45_359_237 / 1_600_000_000; "oz", "ounce", "ounces";
+        @pound: 4.535_923_7_E-1 => 45_359_237 / 100_000_000; "lb", "pound", "pounds";
+        @slug: 1.459_390_E1; "slug", "slug", "slugs";
+        @stone: 6.350_293_E0; "st", "stone", "stones";
+        @ton_long: 1.016_047_E3; "long tn", "long ton", "long tons";
+        @ton_short: 9.071_847_E2; "sh tn", "short ton", "short tons";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        types: Float;
+
+        use si::quantities::*;
+        use si::mass as m;
+        use tests::Test;
+
+        #[test]
+        fn check_units() {
+            Test::assert_approx_eq(&200.0, &Mass::new::<m::carat>(1.0).get(m::milligram));
+            Test::assert_approx_eq(&64.798_91, &Mass::new::<m::grain>(1.0).get(m::milligram));
+            Test::assert_approx_eq(&28.349_523_125, &Mass::new::<m::ounce>(1.0).get(m::gram));
+            Test::assert_approx_eq(&0.453_592_37, &Mass::new::<m::pound>(1.0).get(m::kilogram));
+            Test::assert_approx_eq(&14.593_90, &Mass::new::<m::slug>(1.0).get(m::kilogram));
+            Test::assert_approx_eq(&6.350_293, &Mass::new::<m::stone>(1.0).get(m::kilogram));
+            Test::assert_approx_eq(&1_016.047, &Mass::new::<m::ton_long>(1.0).get(m::kilogram));
+            Test::assert_approx_eq(&907.184_7, &Mass::new::<m::ton_short>(1.0).get(m::kilogram));
+        }
     }
 }
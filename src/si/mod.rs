@@ -7,6 +7,9 @@
 #[macro_use]
 mod prefix;
 
+pub mod constants;
+pub mod level;
+
 system! {
     /// [International System of Quantities](http://jcgm.bipm.org/vim/en/1.6.html) (ISQ).
     quantities: ISQ {
@@ -23,20 +26,68 @@ system! {
     units: SI {
         acceleration::Acceleration,
         amount_of_substance::AmountOfSubstance,
+        angle::Angle,
         area::Area,
+        capacitance::Capacitance,
+        electric_charge::ElectricCharge,
         electric_current::ElectricCurrent,
+        electric_field::ElectricField,
+        electric_potential::ElectricPotential,
+        electrical_conductance::ElectricalConductance,
+        electrical_resistance::ElectricalResistance,
+        energy::Energy,
         force::Force,
         frequency::Frequency,
+        inductance::Inductance,
         length::Length,
         luminous_intensity::LuminousIntensity,
+        magnetic_field_strength::MagneticFieldStrength,
+        magnetic_flux::MagneticFlux,
+        magnetic_flux_density::MagneticFluxDensity,
         mass::Mass,
+        momentum::Momentum,
+        power::Power,
+        pressure::Pressure,
+        ratio::Ratio,
+        solid_angle::SolidAngle,
+        temperature_interval::TemperatureInterval,
         thermodynamic_temperature::ThermodynamicTemperature,
         time::Time,
+        torque::Torque,
         velocity::Velocity,
         volume::Volume,
     }
 }
 
+/// [Kinds](https://jcgm.bipm.org/vim/en/1.2.html) of quantities in the SI that are distinguished
+/// from quantities of the same dimension. See [`Kind`](../../trait.Kind.html).
+pub mod marker {
+    /// Kind of plane angle. Angles support all operations but can't be mixed with a
+    /// [`Ratio`](../ratio/type.Ratio.html) or a
+    /// [`SolidAngle`](../solid_angle/type.SolidAngle.html).
+    pub use AngleKind;
+
+    /// Kind of solid angle. Solid angles support all operations but can't be mixed with a
+    /// [`Ratio`](../ratio/type.Ratio.html) or an [`Angle`](../angle/type.Angle.html).
+    pub trait SolidAngleKind: ::Kind {}
+
+    /// Kind of torque. Torque supports all operations but can't be mixed with an
+    /// [`Energy`](../energy/type.Energy.html).
+    pub trait TorqueKind: ::Kind {}
+
+    /// Kind of thermodynamic temperature. Absolute temperatures can't be added to each other or
+    /// negated. A [`TemperatureInterval`](../temperature_interval/type.TemperatureInterval.html)
+    /// can be added to or subtracted from an absolute temperature and the difference of two
+    /// absolute temperatures is a `TemperatureInterval`.
+    pub trait TemperatureKind
+        : ::marker::Mul
+        + ::marker::MulAssign
+        + ::marker::Div
+        + ::marker::DivAssign
+    {
+    }
+}
+
 /// [`Quantity`](struct.Quantity.html) type aliases using the default base units and parameterized
 /// on the underlying storage type.
 pub mod quantities {
@@ -0,0 +1,72 @@
+//! Momentum (base unit kilogram meter per second, kg · m · s<sup>-1</sup>).
+
+quantity! {
+    /// Momentum (base unit kilogram meter per second, kg · m · s<sup>-1</sup>).
+    quantity: Momentum; "momentum";
+    /// Momentum dimension, kg · m · s<sup>-1</sup>.
+    dimension: ISQ<
+        P1,     // length
+        P1,     // mass
+        N1,     // time
+        Z0,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    units {
+        @kilogram_meter_per_second: prefix!(none); "kg · m/s", "kilogram meter per second",
+            "kilogram meters per second";
+        @gram_centimeter_per_second: prefix!(milli) * prefix!(centi); "g · cm/s",
+            "gram centimeter per second", "gram centimeters per second";
+        @newton_second: prefix!(none); "N · s", "newton second", "newton seconds";
+        @pound_foot_per_second: 1.382_550_E-1; "lb · ft/s", "pound foot per second",
+            "pound feet per second";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        use num::One;
+        use si::quantities::*;
+        use si::force as f;
+        use si::mass as m;
+        use si::momentum as p;
+        use si::time as t;
+        use si::velocity as v;
+
+        #[test]
+        fn check_dimension() {
+            let _: Momentum<V> = Mass::new::<m::kilogram>(V::one())
+                * Velocity::new::<v::meter_per_second>(V::one());
+            let _: Force<V> = Momentum::new::<p::kilogram_meter_per_second>(V::one())
+                / Time::new::<t::second>(V::one());
+        }
+
+        #[test]
+        fn check_units() {
+            assert_eq!(Momentum::new::<p::kilogram_meter_per_second>(V::one()),
+                Mass::new::<m::kilogram>(V::one())
+                    * Velocity::new::<v::meter_per_second>(V::one()));
+            assert_eq!(Momentum::new::<p::newton_second>(V::one()),
+                Force::new::<f::newton>(V::one()) * Time::new::<t::second>(V::one()));
+        }
+    }
+
+    mod float {
+        storage_types! {
+            types: Float;
+
+            use si::quantities::*;
+            use si::momentum as p;
+            use tests::Test;
+
+            #[test]
+            fn check_units() {
+                Test::assert_approx_eq(&1.0E5,
+                    &Momentum::new::<p::kilogram_meter_per_second>(1.0).get(p::gram_centimeter_per_second));
+                Test::assert_approx_eq(&0.138_255,
+                    &Momentum::new::<p::pound_foot_per_second>(1.0).get(p::kilogram_meter_per_second));
+            }
+        }
+    }
+}
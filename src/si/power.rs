@@ -0,0 +1,127 @@
+//! Power (base unit watt, kg · m<sup>2</sup> · s<sup>-3</sup>).
+
+quantity! {
+    /// Power (base unit watt, kg · m<sup>2</sup> · s<sup>-3</sup>).
+    quantity: Power; "power";
+    /// Power dimension, kg · m<sup>2</sup> · s<sup>-3</sup>.
+    dimension: ISQ<
+        P2,     // length
+        P1,     // mass
+        N3,     // time
+        Z0,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    units {
+        @yottawatt: prefix!(yotta); "YW", "yottawatt", "yottawatts";
+        @zettawatt: prefix!(zetta); "ZW", "zettawatt", "zettawatts";
+        @exawatt: prefix!(exa); "EW", "exawatt", "exawatts";
+        @petawatt: prefix!(peta); "PW", "petawatt", "petawatts";
+        @terawatt: prefix!(tera); "TW", "terawatt", "terawatts";
+        @gigawatt: prefix!(giga); "GW", "gigawatt", "gigawatts";
+        @megawatt: prefix!(mega); "MW", "megawatt", "megawatts";
+        @kilowatt: prefix!(kilo); "kW", "kilowatt", "kilowatts";
+        @hectowatt: prefix!(hecto); "hW", "hectowatt", "hectowatts";
+        @decawatt: prefix!(deca); "daW", "decawatt", "decawatts";
+        /// Derived unit of power.
+        @watt: prefix!(none); "W", "watt", "watts";
+        @deciwatt: prefix!(deci); "dW", "deciwatt", "deciwatts";
+        @centiwatt: prefix!(centi); "cW", "centiwatt", "centiwatts";
+        @milliwatt: prefix!(milli); "mW", "milliwatt", "milliwatts";
+        @microwatt: prefix!(micro); "µW", "microwatt", "microwatts";
+        @nanowatt: prefix!(nano); "nW", "nanowatt", "nanowatts";
+        @picowatt: prefix!(pico); "pW", "picowatt", "picowatts";
+        @femtowatt: prefix!(femto); "fW", "femtowatt", "femtowatts";
+        @attowatt: prefix!(atto); "aW", "attowatt", "attowatts";
+        @zeptowatt: prefix!(zepto); "zW", "zeptowatt", "zeptowatts";
+        @yoctowatt: prefix!(yocto); "yW", "yoctowatt", "yoctowatts";
+
+        @british_thermal_unit_per_hour: 2.930_711_E-1; "Btu/h", "British thermal unit per hour",
+            "British thermal units per hour";
+        @erg_per_second: 1.0_E-7; "erg/s", "erg per second", "ergs per second";
+        /// Mechanical horsepower, 550 ft · lbf/s.
+        @horsepower: 7.456_999_E2; "hp", "horsepower", "horsepower";
+        @horsepower_boiler: 9.809_50_E3; "hp (boiler)", "horsepower (boiler)",
+            "horsepower (boiler)";
+        @horsepower_electric: 7.46_E2; "hp (electric)", "horsepower (electric)",
+            "horsepower (electric)";
+        @horsepower_metric: 7.354_988_E2; "hp (metric)", "horsepower (metric)",
+            "horsepower (metric)";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        use num::One;
+        use si::quantities::*;
+        use si::energy as e;
+        use si::power as p;
+        use si::time as t;
+
+        #[test]
+        fn check_dimension() {
+            let _: Power<V> = Energy::new::<e::joule>(V::one())
+                / Time::new::<t::second>(V::one());
+        }
+
+        #[test]
+        fn check_units() {
+            test(e::yottajoule, p::yottawatt);
+            test(e::zettajoule, p::zettawatt);
+            test(e::exajoule, p::exawatt);
+            test(e::petajoule, p::petawatt);
+            test(e::terajoule, p::terawatt);
+            test(e::gigajoule, p::gigawatt);
+            test(e::megajoule, p::megawatt);
+            test(e::kilojoule, p::kilowatt);
+            test(e::hectojoule, p::hectowatt);
+            test(e::decajoule, p::decawatt);
+            test(e::joule, p::watt);
+            test(e::decijoule, p::deciwatt);
+            test(e::centijoule, p::centiwatt);
+            test(e::millijoule, p::milliwatt);
+            test(e::microjoule, p::microwatt);
+            test(e::nanojoule, p::nanowatt);
+            test(e::picojoule, p::picowatt);
+            test(e::femtojoule, p::femtowatt);
+            test(e::attojoule, p::attowatt);
+            test(e::zeptojoule, p::zeptowatt);
+            test(e::yoctojoule, p::yoctowatt);
+
+            fn test<E: e::Conversion<V>, P: p::Conversion<V>>(_e: E, _p: P) {
+                // Skip prefixes that are out of range for the storage type.
+                if <E as ::Conversion<V>>::try_conversion().is_some()
+                    && <P as ::Conversion<V>>::try_conversion().is_some()
+                {
+                    assert_eq!(Power::new::<P>(V::one()),
+                        Energy::new::<E>(V::one()) / Time::new::<t::second>(V::one()));
+                }
+            }
+        }
+    }
+
+    mod float {
+        storage_types! {
+            types: Float;
+
+            use si::quantities::*;
+            use si::power as p;
+            use tests::Test;
+
+            #[test]
+            fn check_units() {
+                Test::assert_approx_eq(&745.699_9, &Power::new::<p::horsepower>(1.0).get(p::watt));
+                Test::assert_approx_eq(&9_809.5,
+                    &Power::new::<p::horsepower_boiler>(1.0).get(p::watt));
+                Test::assert_approx_eq(&746.0,
+                    &Power::new::<p::horsepower_electric>(1.0).get(p::watt));
+                Test::assert_approx_eq(&735.498_8,
+                    &Power::new::<p::horsepower_metric>(1.0).get(p::watt));
+                Test::assert_approx_eq(&293.071_1,
+                    &Power::new::<p::british_thermal_unit_per_hour>(1.0).get(p::milliwatt));
+                Test::assert_approx_eq(&1.0E7, &Power::new::<p::watt>(1.0).get(p::erg_per_second));
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Pressure (base unit pascal, kg · m<sup>-1</sup> · s<sup>-2</sup>).
+
+quantity! {
+    /// Pressure (base unit pascal, kg · m<sup>-1</sup> · s<sup>-2</sup>).
+    quantity: Pressure; "pressure";
+    /// Pressure dimension, kg · m<sup>-1</sup> · s<sup>-2</sup>.
+    dimension: ISQ<
+        N1,     // length
+        P1,     // mass
+        N2,     // time
+        Z0,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    units {
+        @yottapascal: prefix!(yotta); "YPa", "yottapascal", "yottapascals";
+        @zettapascal: prefix!(zetta); "ZPa", "zettapascal", "zettapascals";
+        @exapascal: prefix!(exa); "EPa", "exapascal", "exapascals";
+        @petapascal: prefix!(peta); "PPa", "petapascal", "petapascals";
+        @terapascal: prefix!(tera); "TPa", "terapascal", "terapascals";
+        @gigapascal: prefix!(giga); "GPa", "gigapascal", "gigapascals";
+        @megapascal: prefix!(mega); "MPa", "megapascal", "megapascals";
+        @kilopascal: prefix!(kilo); "kPa", "kilopascal", "kilopascals";
+        @hectopascal: prefix!(hecto); "hPa", "hectopascal", "hectopascals";
+        @decapascal: prefix!(deca); "daPa", "decapascal", "decapascals";
+        /// Derived unit of pressure.
+        @pascal: prefix!(none); "Pa", "pascal", "pascals";
+        @decipascal: prefix!(deci); "dPa", "decipascal", "decipascals";
+        @centipascal: prefix!(centi); "cPa", "centipascal", "centipascals";
+        @millipascal: prefix!(milli); "mPa", "millipascal", "millipascals";
+        @micropascal: prefix!(micro); "µPa", "micropascal", "micropascals";
+        @nanopascal: prefix!(nano); "nPa", "nanopascal", "nanopascals";
+        @picopascal: prefix!(pico); "pPa", "picopascal", "picopascals";
+        @femtopascal: prefix!(femto); "fPa", "femtopascal", "femtopascals";
+        @attopascal: prefix!(atto); "aPa", "attopascal", "attopascals";
+        @zeptopascal: prefix!(zepto); "zPa", "zeptopascal", "zeptopascals";
+        @yoctopascal: prefix!(yocto); "yPa", "yoctopascal", "yoctopascals";
+
+        @atmosphere: 1.013_25_E5; "atm", "atmosphere", "atmospheres";
+        @bar: 1.0_E5; "bar", "bar", "bars";
+        @inch_of_mercury: 3.386_389_E3; "inHg", "inch of mercury", "inches of mercury";
+        @millibar: 1.0_E2; "mbar", "millibar", "millibars";
+        @millimeter_of_mercury: 1.333_224_E2; "mmHg", "millimeter of mercury",
+            "millimeters of mercury";
+        @pound_force_per_square_inch: 6.894_757_E3; "psi", "pound-force per square inch",
+            "pounds-force per square inch";
+        @torr: 1.333_224_E2; "Torr", "torr", "torrs";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        use num::One;
+        use si::quantities::*;
+        use si::area as a;
+        use si::force as f;
+        use si::pressure as p;
+
+        #[test]
+        fn check_dimension() {
+            let _: Pressure<V> = Force::new::<f::newton>(V::one())
+                / Area::new::<a::square_meter>(V::one());
+        }
+
+        #[test]
+        fn check_units() {
+            test(f::yottanewton, p::yottapascal);
+            test(f::zettanewton, p::zettapascal);
+            test(f::exanewton, p::exapascal);
+            test(f::petanewton, p::petapascal);
+            test(f::teranewton, p::terapascal);
+            test(f::giganewton, p::gigapascal);
+            test(f::meganewton, p::megapascal);
+            test(f::kilonewton, p::kilopascal);
+            test(f::hectonewton, p::hectopascal);
+            test(f::decanewton, p::decapascal);
+            test(f::newton, p::pascal);
+            test(f::decinewton, p::decipascal);
+            test(f::centinewton, p::centipascal);
+            test(f::millinewton, p::millipascal);
+            test(f::micronewton, p::micropascal);
+            test(f::nanonewton, p::nanopascal);
+            test(f::piconewton, p::picopascal);
+            test(f::femtonewton, p::femtopascal);
+            test(f::attonewton, p::attopascal);
+            test(f::zeptonewton, p::zeptopascal);
+            test(f::yoctonewton, p::yoctopascal);
+
+            fn test<F: f::Conversion<V>, P: p::Conversion<V>>(_f: F, _p: P) {
+                // Skip prefixes that are out of range for the storage type.
+                if <F as ::Conversion<V>>::try_conversion().is_some()
+                    && <P as ::Conversion<V>>::try_conversion().is_some()
+                {
+                    assert_eq!(Pressure::new::<P>(V::one()),
+                        Force::new::<F>(V::one()) / Area::new::<a::square_meter>(V::one()));
+                }
+            }
+        }
+    }
+
+    mod float {
+        storage_types! {
+            types: Float;
+
+            use si::quantities::*;
+            use si::pressure as p;
+            use tests::Test;
+
+            #[test]
+            fn check_units() {
+                Test::assert_approx_eq(&100.0, &Pressure::new::<p::bar>(1.0).get(p::kilopascal));
+                Test::assert_approx_eq(&1000.0, &Pressure::new::<p::bar>(1.0).get(p::millibar));
+                Test::assert_approx_eq(&101.325,
+                    &Pressure::new::<p::atmosphere>(1.0).get(p::kilopascal));
+                Test::assert_approx_eq(&6.894_757,
+                    &Pressure::new::<p::pound_force_per_square_inch>(1.0).get(p::kilopascal));
+                Test::assert_approx_eq(&1.0,
+                    &Pressure::new::<p::torr>(1.0).get(p::millimeter_of_mercury));
+                Test::assert_approx_eq(&3_386.389,
+                    &Pressure::new::<p::inch_of_mercury>(1.0).get(p::pascal));
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Ratio (dimensionless quantity).
+
+quantity! {
+    /// Ratio (dimensionless quantity).
+    quantity: Ratio; "ratio";
+    /// Ratio dimension, 1.
+    dimension: ISQ<
+        Z0,     // length
+        Z0,     // mass
+        Z0,     // time
+        Z0,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    units {
+        @ratio: 1.0_E0; "", "ratio", "ratios";
+        @percent: 1.0_E-2; "%", "percent", "percent";
+        @per_mille: 1.0_E-3; "‰", "per mille", "per mille";
+        @part_per_million: 1.0_E-6; "ppm", "part per million", "parts per million";
+        @part_per_billion: 1.0_E-9; "ppb", "part per billion", "parts per billion";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        types: Float;
+
+        use si::quantities::*;
+        use si::ratio as r;
+        use str::ParseQuantityError;
+        use tests::Test;
+
+        #[test]
+        fn check_units() {
+            Test::assert_approx_eq(&100.0, &Ratio::new::<r::ratio>(1.0).get(r::percent));
+            Test::assert_approx_eq(&1000.0, &Ratio::new::<r::ratio>(1.0).get(r::per_mille));
+            Test::assert_approx_eq(&1000.0,
+                &Ratio::new::<r::per_mille>(1.0).get(r::part_per_million));
+            Test::assert_approx_eq(&1000.0,
+                &Ratio::new::<r::part_per_million>(1.0).get(r::part_per_billion));
+        }
+
+        #[test]
+        fn from_str() {
+            assert_eq!(Ok(Ratio::new::<r::ratio>(0.5)), "0.5".parse::<Ratio<V>>());
+            assert_eq!(Ok(Ratio::new::<r::percent>(5.0)), "5 %".parse::<Ratio<V>>());
+            assert_eq!(Err(ParseQuantityError::UnknownUnit), "5".parse::<Length<V>>());
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Solid angle (base unit steradian, 1).
+
+quantity! {
+    /// Solid angle (base unit steradian, 1). Solid angles are dimensionless but are of a different
+    /// [kind](../marker/trait.SolidAngleKind.html) than [`Ratio`](../ratio/type.Ratio.html) and
+    /// [`Angle`](../angle/type.Angle.html).
+    ///
+    #[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust,compile_fail")]
+    #[cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+    /// # use uom::si::f32::*;
+    /// # use uom::si::angle::radian;
+    /// # use uom::si::solid_angle::steradian;
+    /// // error[E0308]: mismatched types
+    /// let a = SolidAngle::new::<steradian>(1.0) + Angle::new::<radian>(1.0);
+    /// ```
+    quantity: SolidAngle; "solid angle";
+    /// Solid angle dimension, 1.
+    dimension: ISQ<
+        Z0,     // length
+        Z0,     // mass
+        Z0,     // time
+        Z0,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    kind: ::si::marker::SolidAngleKind;
+    units {
+        /// The steradian is the solid angle subtended at the center of a sphere by a portion of
+        /// the surface equal in area to the square of the radius of the sphere.
+        @steradian: 1.0_E0; "sr", "steradian", "steradians";
+        @square_degree: 3.046_174_197_867_086_E-4; "°²", "square degree", "square degrees";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        types: Float;
+
+        use si::quantities::*;
+        use si::solid_angle as sa;
+        use tests::Test;
+
+        #[test]
+        fn check_units() {
+            // A sphere subtends 4π steradians or approximately 41 252.96 square degrees.
+            Test::assert_approx_eq(&4.125_296_124_941_927_E4,
+                &SolidAngle::new::<sa::steradian>(1.256_637_061_435_917_3_E1)
+                    .get(sa::square_degree));
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Temperature interval (base unit kelvin, K<sup>1</sup>).
+
+quantity! {
+    /// Temperature interval (base unit kelvin, K<sup>1</sup>). The difference of two
+    /// [thermodynamic temperatures](../thermodynamic_temperature/type.ThermodynamicTemperature.html).
+    quantity: TemperatureInterval; "temperature interval";
+    /// Temperature interval dimension, K<sup>1</sup>.
+    dimension: ISQ<
+        Z0,     // length
+        Z0,     // mass
+        Z0,     // time
+        Z0,     // electric current
+        P1,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    units {
+        @yottakelvin: prefix!(yotta); "YK", "yottakelvin", "yottakelvins";
+        @zettakelvin: prefix!(zetta); "ZK", "zettakelvin", "zettakelvins";
+        @exakelvin: prefix!(exa); "EK", "exakelvin", "exakelvins";
+        @petakelvin: prefix!(peta); "PK", "petakelvin", "petakelvins";
+        @terakelvin: prefix!(tera); "TK", "terakelvin", "terakelvins";
+        @gigakelvin: prefix!(giga); "GK", "gigakelvin", "gigakelvins";
+        @megakelvin: prefix!(mega); "MK", "megakelvin", "megakelvins";
+        @kilokelvin: prefix!(kilo); "kK", "kilokelvin", "kilokelvins";
+        @hectokelvin: prefix!(hecto); "hK", "hectokelvin", "hectokelvins";
+        @decakelvin: prefix!(deca); "daK", "decakelvin", "decakelvins";
+        @kelvin: prefix!(none); "K", "kelvin", "kelvins";
+        @decikelvin: prefix!(deci); "dK", "decikelvin", "decikelvins";
+        @centikelvin: prefix!(centi); "cK", "centikelvin", "centikelvins";
+        @millikelvin: prefix!(milli); "mK", "millikelvin", "millikelvins";
+        @microkelvin: prefix!(micro); "µK", "microkelvin", "microkelvins";
+        @nanokelvin: prefix!(nano); "nK", "nanokelvin", "nanokelvins";
+        @picokelvin: prefix!(pico); "pK", "picokelvin", "picokelvins";
+        @femtokelvin: prefix!(femto); "fK", "femtokelvin", "femtokelvins";
+        @attokelvin: prefix!(atto); "aK", "attokelvin", "attokelvins";
+        @zeptokelvin: prefix!(zepto); "zK", "zeptokelvin", "zeptokelvins";
+        @yoctokelvin: prefix!(yocto); "yK", "yoctokelvin", "yoctokelvins";
+
+        @degree_celsius: 1.0_E0; "°C", "degree Celsius", "degrees Celsius";
+        @degree_fahrenheit: 5.0_E0 / 9.0_E0 => 5 / 9; "°F", "degree Fahrenheit",
+            "degrees Fahrenheit";
+        @degree_rankine: 5.0_E0 / 9.0_E0 => 5 / 9; "°R", "degree Rankine", "degrees Rankine";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        types: Float;
+
+        use si::quantities::*;
+        use si::temperature_interval as ti;
+        use tests::Test;
+
+        #[test]
+        fn check_units() {
+            Test::assert_approx_eq(&1.0,
+                &TemperatureInterval::new::<ti::kelvin>(1.0).get(ti::degree_celsius));
+            Test::assert_approx_eq(&9.0,
+                &TemperatureInterval::new::<ti::kelvin>(5.0).get(ti::degree_fahrenheit));
+            Test::assert_approx_eq(&9.0,
+                &TemperatureInterval::new::<ti::kelvin>(5.0).get(ti::degree_rankine));
+            Test::assert_approx_eq(&1.0,
+                &TemperatureInterval::new::<ti::degree_fahrenheit>(1.0).get(ti::degree_rankine));
+        }
+    }
+}
@@ -1,7 +1,30 @@
 //! Thermodynamic temperature (base unit kelvin, K<sup>1</sup>).
 
+use super::temperature_interval::{self, TemperatureInterval};
+
 quantity! {
-    /// Thermodynamic temperature (base unit kelvin, K<sup>1</sup>).
+    /// Thermodynamic temperature (base unit kelvin, K<sup>1</sup>). Absolute temperatures can't be
+    /// added to each other. See [`TemperatureKind`](../marker/trait.TemperatureKind.html).
+    ///
+    #[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust")]
+    #[cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+    /// # use uom::si::f32::*;
+    /// # use uom::si::thermodynamic_temperature::{degree_celsius, kelvin};
+    /// # use uom::si::temperature_interval as ti;
+    /// let t1 = ThermodynamicTemperature::new::<degree_celsius>(20.0);
+    /// let t2 = ThermodynamicTemperature::new::<kelvin>(300.0);
+    /// let i: TemperatureInterval = t2 - t1;
+    /// let t3: ThermodynamicTemperature = t1 + TemperatureInterval::new::<ti::degree_celsius>(5.0);
+    /// ```
+    ///
+    #[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust,compile_fail")]
+    #[cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+    /// # use uom::si::f32::*;
+    /// # use uom::si::thermodynamic_temperature::kelvin;
+    /// // error[E0369]: binary operation `+` cannot be applied to type ...
+    /// let t = ThermodynamicTemperature::new::<kelvin>(1.0)
+    ///     + ThermodynamicTemperature::new::<kelvin>(1.0);
+    /// ```
     quantity: ThermodynamicTemperature; "thermodynamic temperature";
     /// Thermodynamic temperature dimension, K<sup>1</sup>.
     dimension: ISQ<
@@ -12,6 +35,7 @@ quantity! {
         P1,     // thermodynamic temperature
         Z0,     // amount of substance
         Z0>;    // luminous intensity
+    kind: ::si::marker::TemperatureKind;
     units {
         @yottakelvin: prefix!(yotta); "YK", "yottakelvin", "yottakelvins";
         @zettakelvin: prefix!(zetta); "ZK", "zettakelvin", "zettakelvins";
@@ -36,5 +60,198 @@ quantity! {
         @attokelvin: prefix!(atto); "aK", "attokelvin", "attokelvins";
         @zeptokelvin: prefix!(zepto); "zK", "zeptokelvin", "zeptokelvins";
         @yoctokelvin: prefix!(yocto); "yK", "yoctokelvin", "yoctokelvins";
+
+        @degree_celsius: 1.0_E0, 2.7315_E2 => 27_315 / 100; "°C", "degree Celsius",
+            "degrees Celsius";
+        @degree_fahrenheit: 5.0_E0 / 9.0_E0 => 5 / 9, 4.5967_E2 => 45_967 / 100; "°F",
+            "degree Fahrenheit", "degrees Fahrenheit";
+        @degree_rankine: 5.0_E0 / 9.0_E0 => 5 / 9; "°R", "degree Rankine", "degrees Rankine";
+    }
+}
+
+impl<Ul, Ur, V> ::lib::ops::Add<TemperatureInterval<Ur, V>> for ThermodynamicTemperature<Ul, V>
+where
+    Ul: super::Units<V> + ?Sized,
+    Ur: super::Units<V> + ?Sized,
+    V: ::num::Num + ::Conversion<V>,
+{
+    type Output = ThermodynamicTemperature<Ul, V>;
+
+    #[inline(always)]
+    fn add(self, rhs: TemperatureInterval<Ur, V>) -> Self::Output {
+        super::Quantity {
+            dimension: ::lib::marker::PhantomData,
+            units: ::lib::marker::PhantomData,
+            value: self.value
+                + super::change_base::<temperature_interval::Dimension, Ul, Ur, V>(&rhs.value),
+        }
+    }
+}
+
+impl<Ul, Ur, V> ::lib::ops::Add<ThermodynamicTemperature<Ur, V>> for TemperatureInterval<Ul, V>
+where
+    Ul: super::Units<V> + ?Sized,
+    Ur: super::Units<V> + ?Sized,
+    V: ::num::Num + ::Conversion<V>,
+{
+    type Output = ThermodynamicTemperature<Ul, V>;
+
+    #[inline(always)]
+    fn add(self, rhs: ThermodynamicTemperature<Ur, V>) -> Self::Output {
+        super::Quantity {
+            dimension: ::lib::marker::PhantomData,
+            units: ::lib::marker::PhantomData,
+            value: self.value + super::change_base::<Dimension, Ul, Ur, V>(&rhs.value),
+        }
+    }
+}
+
+impl<Ul, Ur, V> ::lib::ops::AddAssign<TemperatureInterval<Ur, V>>
+    for ThermodynamicTemperature<Ul, V>
+where
+    Ul: super::Units<V> + ?Sized,
+    Ur: super::Units<V> + ?Sized,
+    V: ::num::Num + ::Conversion<V> + ::lib::ops::AddAssign<V>,
+{
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: TemperatureInterval<Ur, V>) {
+        self.value +=
+            super::change_base::<temperature_interval::Dimension, Ul, Ur, V>(&rhs.value);
+    }
+}
+
+impl<Ul, Ur, V> ::lib::ops::Sub<TemperatureInterval<Ur, V>> for ThermodynamicTemperature<Ul, V>
+where
+    Ul: super::Units<V> + ?Sized,
+    Ur: super::Units<V> + ?Sized,
+    V: ::num::Num + ::Conversion<V>,
+{
+    type Output = ThermodynamicTemperature<Ul, V>;
+
+    #[inline(always)]
+    fn sub(self, rhs: TemperatureInterval<Ur, V>) -> Self::Output {
+        super::Quantity {
+            dimension: ::lib::marker::PhantomData,
+            units: ::lib::marker::PhantomData,
+            value: self.value
+                - super::change_base::<temperature_interval::Dimension, Ul, Ur, V>(&rhs.value),
+        }
+    }
+}
+
+impl<Ul, Ur, V> ::lib::ops::SubAssign<TemperatureInterval<Ur, V>>
+    for ThermodynamicTemperature<Ul, V>
+where
+    Ul: super::Units<V> + ?Sized,
+    Ur: super::Units<V> + ?Sized,
+    V: ::num::Num + ::Conversion<V> + ::lib::ops::SubAssign<V>,
+{
+    #[inline(always)]
+    fn sub_assign(&mut self, rhs: TemperatureInterval<Ur, V>) {
+        self.value -=
+            super::change_base::<temperature_interval::Dimension, Ul, Ur, V>(&rhs.value);
+    }
+}
+
+impl<Ul, Ur, V> ::lib::ops::Sub<ThermodynamicTemperature<Ur, V>>
+    for ThermodynamicTemperature<Ul, V>
+where
+    Ul: super::Units<V> + ?Sized,
+    Ur: super::Units<V> + ?Sized,
+    V: ::num::Num + ::Conversion<V>,
+{
+    type Output = TemperatureInterval<Ul, V>;
+
+    #[inline(always)]
+    fn sub(self, rhs: ThermodynamicTemperature<Ur, V>) -> Self::Output {
+        super::Quantity {
+            dimension: ::lib::marker::PhantomData,
+            units: ::lib::marker::PhantomData,
+            value: self.value - super::change_base::<Dimension, Ul, Ur, V>(&rhs.value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        types: Float;
+
+        use si::quantities::*;
+        use si::temperature_interval as ti;
+        use si::thermodynamic_temperature as tt;
+
+        // Offsets between temperature scales lose precision relative to the machine epsilon.
+        const EPSILON: V = 1.0E-4;
+
+        #[test]
+        fn check_units() {
+            test(tt::kelvin, tt::degree_celsius, 273.15, 0.0);
+            test(tt::kelvin, tt::degree_celsius, 373.15, 100.0);
+            test(tt::kelvin, tt::degree_fahrenheit, 0.0, -459.67);
+            test(tt::kelvin, tt::degree_fahrenheit, 273.15, 32.0);
+            test(tt::kelvin, tt::degree_rankine, 273.15, 491.67);
+            test(tt::degree_celsius, tt::degree_fahrenheit, -40.0, -40.0);
+            test(tt::degree_celsius, tt::degree_fahrenheit, 100.0, 212.0);
+            test(tt::degree_fahrenheit, tt::degree_rankine, 32.0, 491.67);
+
+            fn test<T1: tt::Conversion<V>, T2: tt::Conversion<V>>(u1: T1, u2: T2, v1: V, v2: V) {
+                assert_relative_eq!(v1, ThermodynamicTemperature::new::<T2>(v2).get(u1),
+                    epsilon = EPSILON);
+                assert_relative_eq!(v2, ThermodynamicTemperature::new::<T1>(v1).get(u2),
+                    epsilon = EPSILON);
+            }
+        }
+
+        #[test]
+        fn interval() {
+            let t1 = ThermodynamicTemperature::<V>::new::<tt::degree_celsius>(20.0);
+            let t2 = ThermodynamicTemperature::<V>::new::<tt::degree_fahrenheit>(86.0);
+            let i = TemperatureInterval::<V>::new::<ti::degree_celsius>(10.0);
+            let mut t3 = t1;
+
+            assert_relative_eq!((t2 - t1).get(ti::degree_fahrenheit), 18.0, epsilon = EPSILON);
+            assert_relative_eq!((t1 + i).get(tt::degree_celsius), 30.0, epsilon = EPSILON);
+            assert_relative_eq!((i + t1).get(tt::degree_celsius), 30.0, epsilon = EPSILON);
+            assert_relative_eq!((t2 - i).get(tt::degree_celsius), 20.0, epsilon = EPSILON);
+
+            t3 += i;
+            assert_relative_eq!(t3.get(tt::degree_fahrenheit), 86.0, epsilon = EPSILON);
+            t3 -= i;
+            assert_relative_eq!(t3.get(tt::degree_celsius), 20.0, epsilon = EPSILON);
+        }
+    }
+
+    mod exact {
+        storage_types! {
+            types: Ratio;
+
+            use num::{FromPrimitive, Zero};
+            use si::quantities::*;
+            use si::thermodynamic_temperature as tt;
+
+            #[test]
+            fn check_units() {
+                let v = |v| V::from_u64(v).unwrap();
+
+                assert_eq!(v(27_315) / v(100),
+                    ThermodynamicTemperature::new::<tt::degree_celsius>(V::zero()).get(tt::kelvin));
+                assert_eq!(ThermodynamicTemperature::new::<tt::degree_celsius>(V::zero()),
+                    ThermodynamicTemperature::new::<tt::degree_fahrenheit>(v(32)));
+                assert_eq!(v(212),
+                    ThermodynamicTemperature::new::<tt::degree_celsius>(v(100))
+                        .get(tt::degree_fahrenheit));
+                assert_eq!(v(45_967) / v(100),
+                    ThermodynamicTemperature::new::<tt::degree_fahrenheit>(V::zero())
+                        .get(tt::degree_rankine));
+            }
+        }
+    }
+
+    #[test]
+    fn find_unit() {
+        // Base quantities take precedence over the temperature interval's `K`.
+        assert_eq!(Some("thermodynamic temperature"),
+            ::si::find_unit("K").map(|u| u.description));
     }
 }
@@ -1,4 +1,26 @@
 //! Time (base unit second, s<sup>1</sup>).
+//!
+//! `Time` converts to and from `core::time::Duration`. `From<Duration>` is implemented for
+//! floating point storage types with the `std` feature, or the `try_from` feature for `no_std`
+//! builds. With the `try_from` feature `TryFrom<Duration>` is implemented for primitive integer
+//! storage types and fails when the value overflows the storage type. Nanoseconds are kept when the
+//! base unit of time and the storage type can represent them (e.g. a system of units with
+//! nanosecond as the base unit of time) and truncated otherwise. `TryFrom<Time>` for `Duration` is
+//! implemented for both and fails for negative, NaN, and overflowing values.
+//!
+#![cfg_attr(all(feature = "si", feature = "f64", feature = "try_from"), doc = " ```rust")]
+#![cfg_attr(not(all(feature = "si", feature = "f64", feature = "try_from")),
+    doc = " ```rust,ignore")]
+//! # use std::convert::TryFrom;
+//! # use std::time::Duration;
+//! # use uom::si::f64::*;
+//! # use uom::si::time::{millisecond, DurationError};
+//! let t = Time::from(Duration::from_millis(1_500));
+//!
+//! assert_eq!(1_500.0, t.get(millisecond));
+//! assert_eq!(Ok(Duration::from_millis(250)), Duration::try_from(Time::new::<millisecond>(250.0)));
+//! assert_eq!(Err(DurationError::Negative), Duration::try_from(-t));
+//! ```
 
 quantity! {
     /// Time (base unit second, s<sup>1</sup>).
@@ -44,3 +66,302 @@ quantity! {
         @year: 3.1536_E7; "a", "year", "years";
     }
 }
+
+/// Error returned when converting a `Time` into a `Duration` fails.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DurationError {
+    /// The time is negative.
+    Negative,
+    /// The time is NaN.
+    NaN,
+    /// The time overflows a `Duration`.
+    Overflow,
+}
+
+impl ::lib::fmt::Display for DurationError {
+    fn fmt(&self, f: &mut ::lib::fmt::Formatter) -> ::lib::fmt::Result {
+        match *self {
+            DurationError::Negative => write!(f, "negative time"),
+            DurationError::NaN => write!(f, "time is NaN"),
+            DurationError::Overflow => write!(f, "time overflows duration"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::lib::error::Error for DurationError {
+    fn description(&self) -> &str {
+        match *self {
+            DurationError::Negative => "negative time",
+            DurationError::NaN => "time is NaN",
+            DurationError::Overflow => "time overflows duration",
+        }
+    }
+}
+
+// `core::time` requires rustc 1.25.0 so `no_std` builds only convert with the `try_from` feature.
+#[cfg(any(feature = "std", feature = "try_from"))]
+mod duration {
+    storage_types! {
+        types: Float;
+
+        use lib::time::Duration;
+        use si::time::{second, Time};
+
+        impl<U> From<Duration> for Time<U, V>
+        where
+            U: ::si::Units<V> + ?Sized,
+        {
+            #[inline(always)]
+            #[allow(trivial_numeric_casts)]
+            fn from(val: Duration) -> Self {
+                Time::new::<second>(
+                    (val.as_secs() as f64 + f64::from(val.subsec_nanos()) * 1.0_E-9) as V)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "try_from")]
+mod try_from_duration {
+    storage_types! {
+        types: Float;
+
+        use lib::convert::TryFrom;
+        use lib::time::Duration;
+        use si::time::{second, DurationError, Time};
+
+        impl<U> TryFrom<Time<U, V>> for Duration
+        where
+            U: ::si::Units<V> + ?Sized,
+        {
+            type Error = DurationError;
+
+            #[allow(trivial_numeric_casts)]
+            fn try_from(val: Time<U, V>) -> Result<Self, Self::Error> {
+                let secs = val.get(second) as f64;
+
+                // 2^64 is the first value that overflows `u64`.
+                if secs.is_nan() {
+                    return Err(DurationError::NaN);
+                } else if secs < 0.0 {
+                    return Err(DurationError::Negative);
+                } else if secs >= 18_446_744_073_709_551_616.0 {
+                    return Err(DurationError::Overflow);
+                }
+
+                let whole = secs as u64;
+                let nanos = ((secs - whole as f64) * 1.0_E9 + 0.5) as u32;
+
+                if nanos < 1_000_000_000 {
+                    Ok(Duration::new(whole, nanos))
+                } else {
+                    whole.checked_add(1)
+                        .map(|s| Duration::new(s, 0))
+                        .ok_or(DurationError::Overflow)
+                }
+            }
+        }
+    }
+
+    storage_types! {
+        types: PrimInt;
+
+        use lib::convert::TryFrom;
+        use lib::time::Duration;
+        use num::{NumCast, ToPrimitive, Zero};
+        use si::time::{microsecond, millisecond, nanosecond, second, DurationError, Time};
+        use ConversionError;
+
+        impl<U> TryFrom<Duration> for Time<U, V>
+        where
+            U: ::si::Units<V> + ?Sized,
+        {
+            type Error = ConversionError;
+
+            fn try_from(val: Duration) -> Result<Self, Self::Error> {
+                let secs = <V as NumCast>::from(val.as_secs()).ok_or(ConversionError::Overflow)?;
+                let secs = Time::<U, V>::try_new::<second>(secs)?;
+                let nanos = val.subsec_nanos();
+
+                // Use the finest unit the storage type can represent for the fractional second.
+                let nanos = <V as NumCast>::from(nanos)
+                    .map(Time::<U, V>::new::<nanosecond>)
+                    .or_else(|| <V as NumCast>::from(nanos / 1_000)
+                        .map(Time::<U, V>::new::<microsecond>))
+                    .or_else(|| <V as NumCast>::from(nanos / 1_000_000)
+                        .map(Time::<U, V>::new::<millisecond>))
+                    .unwrap_or_else(Time::<U, V>::zero);
+
+                secs.value.checked_add(nanos.value)
+                    .map(|v| Time {
+                        dimension: ::lib::marker::PhantomData,
+                        units: ::lib::marker::PhantomData,
+                        value: v,
+                    })
+                    .ok_or(ConversionError::Overflow)
+            }
+        }
+
+        impl<U> TryFrom<Time<U, V>> for Duration
+        where
+            U: ::si::Units<V> + ?Sized,
+        {
+            type Error = DurationError;
+
+            fn try_from(val: Time<U, V>) -> Result<Self, Self::Error> {
+                if val.value < V::zero() {
+                    return Err(DurationError::Negative);
+                }
+
+                let secs = val.get(second);
+                let rem = val - Time::<U, V>::new::<second>(secs);
+                let nanos = if rem.value.is_zero() { V::zero() } else { rem.get(nanosecond) };
+
+                match (secs.to_u64(), nanos.to_u32()) {
+                    (Some(s), Some(n)) => Ok(Duration::new(s, n)),
+                    _ => Err(DurationError::Overflow),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    mod mixed {
+        storage_types! {
+            types: Float;
+
+            use si::quantities::*;
+            use si::time::{hour, minute, second};
+            use tests::Test;
+
+            #[test]
+            fn mixed() {
+                let hms = (hour, minute, second);
+                let m = Time::new::<second>(3_600.0).into_mixed(hms);
+
+                Test::assert_eq(&1.0, &m.values.0);
+                Test::assert_eq(&0.0, &m.values.1);
+                Test::assert_eq(&0.0, &m.values.2);
+                assert_eq!("1 h 0 min 0 s", format!("{}", m));
+                assert_eq!("2 h 0 min 0 s",
+                    format!("{:.0}", Time::new::<second>(7_199.6).into_mixed(hms)));
+                assert_eq!("1 h 59 min 59.6 s",
+                    format!("{:.1}", Time::new::<second>(7_199.6).into_mixed(hms)));
+
+                let m = Time::new::<second>(-3_661.0).into_mixed(hms);
+
+                assert_eq!("-1 h 1 min 1 s", format!("{:.0}", m));
+                Test::assert_approx_eq(&-3_661.0, &Time::from_mixed(hms, m.values).get(second));
+            }
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "try_from"))]
+    mod from {
+        storage_types! {
+            types: Float;
+
+            use lib::time::Duration;
+            use si::quantities::*;
+            use si::time as t;
+            use tests::Test;
+
+            #[test]
+            fn from_duration() {
+                Test::assert_approx_eq(&1.5,
+                    &Time::from(Duration::new(1, 500_000_000)).get(t::second));
+                Test::assert_approx_eq(&0.25,
+                    &Time::from(Duration::from_millis(250)).get(t::second));
+            }
+        }
+    }
+
+    #[cfg(feature = "try_from")]
+    mod float {
+        storage_types! {
+            types: Float;
+
+            use lib::convert::TryFrom;
+            use lib::time::Duration;
+            use num::Float;
+            use si::quantities::*;
+            use si::time::{self as t, DurationError};
+
+            #[test]
+            fn try_into_duration() {
+                assert_eq!(Ok(Duration::new(2, 0)),
+                    Duration::try_from(Time::new::<t::second>(2.0)));
+                assert_eq!(Ok(Duration::from_millis(250)),
+                    Duration::try_from(Time::new::<t::millisecond>(250.0)));
+                assert_eq!(Ok(Duration::new(0, 0)),
+                    Duration::try_from(Time::new::<t::second>(-0.0)));
+                assert_eq!(Err(DurationError::Negative),
+                    Duration::try_from(Time::new::<t::second>(-1.0)));
+                assert_eq!(Err(DurationError::NaN),
+                    Duration::try_from(Time::new::<t::second>(V::nan())));
+                assert_eq!(Err(DurationError::Overflow),
+                    Duration::try_from(Time::new::<t::second>(V::infinity())));
+                assert_eq!(Err(DurationError::Overflow),
+                    Duration::try_from(Time::new::<t::second>(1.0_E20)));
+            }
+        }
+    }
+
+    #[cfg(feature = "try_from")]
+    mod prim_int {
+        storage_types! {
+            types: PrimInt;
+
+            use lib::convert::TryFrom;
+            use lib::time::Duration;
+            use num::{FromPrimitive, One, Zero};
+            use si::Units;
+            use si::length::meter;
+            use si::mass::kilogram;
+            use si::electric_current::ampere;
+            use si::thermodynamic_temperature::kelvin;
+            use si::amount_of_substance::mole;
+            use si::luminous_intensity::candela;
+            use si::quantities::*;
+            use si::time as t;
+            use ConversionError;
+
+            #[test]
+            fn try_from_duration() {
+                assert_eq!(Ok(Time::new::<t::second>(V::one())),
+                    Time::try_from(Duration::new(1, 999_999_999)).map_err(|_| ()));
+                assert_eq!(Ok(Time::new::<t::second>(V::one() + V::one())),
+                    Time::try_from(Duration::new(2, 5)).map_err(|_| ()));
+            }
+
+            #[test]
+            fn try_into_duration() {
+                assert_eq!(Ok(Duration::new(1, 0)),
+                    Duration::try_from(Time::new::<t::second>(V::one())));
+                assert_eq!(Ok(Duration::new(0, 0)),
+                    Duration::try_from(Time::new::<t::second>(V::zero())));
+            }
+
+            #[test]
+            fn nanosecond_precision() {
+                type Nano = Units<V, length = meter, mass = kilogram, time = t::nanosecond,
+                    electric_current = ampere, thermodynamic_temperature = kelvin,
+                    amount_of_substance = mole, luminous_intensity = candela>;
+
+                if let Some(v) = V::from_u64(1_500_000_000) {
+                    let d = Duration::new(1, 500_000_000);
+                    let n = t::Time::<Nano, V>::try_from(d);
+
+                    assert_eq!(Ok(v), n.map(|n| n.value));
+                    assert_eq!(Ok(d), Duration::try_from(n.unwrap()));
+                }
+
+                assert_eq!(Err(ConversionError::Overflow),
+                    t::Time::<Nano, V>::try_from(Duration::new(u64::max_value(), 0)));
+            }
+        }
+    }
+}
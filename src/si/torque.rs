@@ -0,0 +1,123 @@
+//! Torque (base unit newton meter, kg · m<sup>2</sup> · s<sup>-2</sup>).
+
+quantity! {
+    /// Torque (base unit newton meter, kg · m<sup>2</sup> · s<sup>-2</sup>). Torque has the same
+    /// dimension as [`Energy`](../energy/type.Energy.html) but is of a different
+    /// [kind](../marker/trait.TorqueKind.html) so that the two can't be mixed.
+    ///
+    #[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust,compile_fail")]
+    #[cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+    /// # use uom::si::f32::*;
+    /// # use uom::si::energy::joule;
+    /// # use uom::si::torque::newton_meter;
+    /// // error[E0308]: mismatched types
+    /// let t = Torque::new::<newton_meter>(1.0) + Energy::new::<joule>(1.0);
+    /// ```
+    quantity: Torque; "torque";
+    /// Torque dimension, kg · m<sup>2</sup> · s<sup>-2</sup>.
+    dimension: ISQ<
+        P2,     // length
+        P1,     // mass
+        N2,     // time
+        Z0,     // electric current
+        Z0,     // thermodynamic temperature
+        Z0,     // amount of substance
+        Z0>;    // luminous intensity
+    kind: ::si::marker::TorqueKind;
+    units {
+        @yottanewton_meter: prefix!(yotta); "YN · m", "yottanewton meter", "yottanewton meters";
+        @zettanewton_meter: prefix!(zetta); "ZN · m", "zettanewton meter", "zettanewton meters";
+        @exanewton_meter: prefix!(exa); "EN · m", "exanewton meter", "exanewton meters";
+        @petanewton_meter: prefix!(peta); "PN · m", "petanewton meter", "petanewton meters";
+        @teranewton_meter: prefix!(tera); "TN · m", "teranewton meter", "teranewton meters";
+        @giganewton_meter: prefix!(giga); "GN · m", "giganewton meter", "giganewton meters";
+        @meganewton_meter: prefix!(mega); "MN · m", "meganewton meter", "meganewton meters";
+        @kilonewton_meter: prefix!(kilo); "kN · m", "kilonewton meter", "kilonewton meters";
+        @hectonewton_meter: prefix!(hecto); "hN · m", "hectonewton meter", "hectonewton meters";
+        @decanewton_meter: prefix!(deca); "daN · m", "decanewton meter", "decanewton meters";
+        @newton_meter: prefix!(none); "N · m", "newton meter", "newton meters";
+        @decinewton_meter: prefix!(deci); "dN · m", "decinewton meter", "decinewton meters";
+        @centinewton_meter: prefix!(centi); "cN · m", "centinewton meter", "centinewton meters";
+        @millinewton_meter: prefix!(milli); "mN · m", "millinewton meter", "millinewton meters";
+        @micronewton_meter: prefix!(micro); "µN · m", "micronewton meter", "micronewton meters";
+        @nanonewton_meter: prefix!(nano); "nN · m", "nanonewton meter", "nanonewton meters";
+        @piconewton_meter: prefix!(pico); "pN · m", "piconewton meter", "piconewton meters";
+        @femtonewton_meter: prefix!(femto); "fN · m", "femtonewton meter", "femtonewton meters";
+        @attonewton_meter: prefix!(atto); "aN · m", "attonewton meter", "attonewton meters";
+        @zeptonewton_meter: prefix!(zepto); "zN · m", "zeptonewton meter", "zeptonewton meters";
+        @yoctonewton_meter: prefix!(yocto); "yN · m", "yoctonewton meter", "yoctonewton meters";
+
+        @pound_force_foot: 1.355_818_E0; "lbf · ft", "pound-force foot", "pound-force feet";
+        @pound_force_inch: 1.129_848_E-1; "lbf · in", "pound-force inch", "pound-force inches";
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    storage_types! {
+        use num::One;
+        use si::quantities::*;
+        use si::angle as a;
+        use si::energy as e;
+        use si::torque as t;
+
+        #[test]
+        fn check_dimension() {
+            let _: Energy<V> = Torque::new::<t::newton_meter>(V::one())
+                * Angle::new::<a::radian>(V::one());
+        }
+
+        #[test]
+        fn check_units() {
+            test(t::yottanewton_meter, e::yottajoule);
+            test(t::zettanewton_meter, e::zettajoule);
+            test(t::exanewton_meter, e::exajoule);
+            test(t::petanewton_meter, e::petajoule);
+            test(t::teranewton_meter, e::terajoule);
+            test(t::giganewton_meter, e::gigajoule);
+            test(t::meganewton_meter, e::megajoule);
+            test(t::kilonewton_meter, e::kilojoule);
+            test(t::hectonewton_meter, e::hectojoule);
+            test(t::decanewton_meter, e::decajoule);
+            test(t::newton_meter, e::joule);
+            test(t::decinewton_meter, e::decijoule);
+            test(t::centinewton_meter, e::centijoule);
+            test(t::millinewton_meter, e::millijoule);
+            test(t::micronewton_meter, e::microjoule);
+            test(t::nanonewton_meter, e::nanojoule);
+            test(t::piconewton_meter, e::picojoule);
+            test(t::femtonewton_meter, e::femtojoule);
+            test(t::attonewton_meter, e::attojoule);
+            test(t::zeptonewton_meter, e::zeptojoule);
+            test(t::yoctonewton_meter, e::yoctojoule);
+
+            fn test<T: t::Conversion<V>, E: e::Conversion<V>>(_t: T, _e: E) {
+                // Skip prefixes that are out of range for the storage type.
+                if <T as ::Conversion<V>>::try_conversion().is_some()
+                    && <E as ::Conversion<V>>::try_conversion().is_some()
+                {
+                    assert_eq!(Energy::new::<E>(V::one()),
+                        Torque::new::<T>(V::one()) * Angle::new::<a::radian>(V::one()));
+                }
+            }
+        }
+    }
+
+    mod float {
+        storage_types! {
+            types: Float;
+
+            use si::quantities::*;
+            use si::torque as t;
+            use tests::Test;
+
+            #[test]
+            fn check_units() {
+                Test::assert_approx_eq(&1.355_818,
+                    &Torque::new::<t::pound_force_foot>(1.0).get(t::newton_meter));
+                Test::assert_approx_eq(&0.112_984_8,
+                    &Torque::new::<t::pound_force_inch>(1.0).get(t::newton_meter));
+            }
+        }
+    }
+}
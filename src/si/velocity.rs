@@ -65,7 +65,6 @@ mod test {
         use si::length as l;
         use si::time as t;
         use si::velocity as v;
-        use tests::Test;
 
         #[test]
         fn check_dimension() {
@@ -97,10 +96,9 @@ mod test {
             test(l::zeptometer, v::zeptometer_per_second);
             test(l::yoctometer, v::yoctometer_per_second);
 
-            // TODO #17 Convert to == once PartialEq is implemented.
-            fn test<L: l::Conversion<V>, E: v::Conversion<V>>(_l: L, v: E) {
-                Test::assert_eq(&V::one(),
-                    &(Length::new::<L>(V::one()) / Time::new::<t::second>(V::one())).get(v));
+            fn test<L: l::Conversion<V>, E: v::Conversion<V>>(_l: L, _v: E) {
+                assert_eq!(Velocity::new::<E>(V::one()),
+                    Length::new::<L>(V::one()) / Time::new::<t::second>(V::one()));
             }
         }
     }
@@ -54,6 +54,42 @@ quantity! {
             "zm³", "cubic zeptometer", "cubic zeptometers";
         @cubic_yoctometer: prefix!(yocto) * prefix!(yocto) * prefix!(yocto);
             "ym³", "cubic yoctometer", "cubic yoctometers";
+        @yottaliter: prefix!(yotta) / prefix!(kilo); "YL", "yottaliter", "yottaliters";
+        @zettaliter: prefix!(zetta) / prefix!(kilo); "ZL", "zettaliter", "zettaliters";
+        @exaliter: prefix!(exa) / prefix!(kilo); "EL", "exaliter", "exaliters";
+        @petaliter: prefix!(peta) / prefix!(kilo); "PL", "petaliter", "petaliters";
+        @teraliter: prefix!(tera) / prefix!(kilo); "TL", "teraliter", "teraliters";
+        @gigaliter: prefix!(giga) / prefix!(kilo); "GL", "gigaliter", "gigaliters";
+        @megaliter: prefix!(mega) / prefix!(kilo); "ML", "megaliter", "megaliters";
+        @kiloliter: prefix!(none); "kL", "kiloliter", "kiloliters";
+        @hectoliter: prefix!(hecto) / prefix!(kilo); "hL", "hectoliter", "hectoliters";
+        @decaliter: prefix!(deca) / prefix!(kilo); "daL", "decaliter", "decaliters";
+        @liter: prefix!(none) / prefix!(kilo); "L", "liter", "liters";
+        @deciliter: prefix!(deci) / prefix!(kilo); "dL", "deciliter", "deciliters";
+        @centiliter: prefix!(centi) / prefix!(kilo); "cL", "centiliter", "centiliters";
+        @milliliter: prefix!(milli) / prefix!(kilo); "mL", "milliliter", "milliliters";
+        @microliter: prefix!(micro) / prefix!(kilo); "µL", "microliter", "microliters";
+        @nanoliter: prefix!(nano) / prefix!(kilo); "nL", "nanoliter", "nanoliters";
+        @picoliter: prefix!(pico) / prefix!(kilo); "pL", "picoliter", "picoliters";
+        @femtoliter: prefix!(femto) / prefix!(kilo); "fL", "femtoliter", "femtoliters";
+        @attoliter: prefix!(atto) / prefix!(kilo); "aL", "attoliter", "attoliters";
+        @zeptoliter: prefix!(zepto) / prefix!(kilo); "zL", "zeptoliter", "zeptoliters";
+        @yoctoliter: prefix!(yocto) / prefix!(kilo); "yL", "yoctoliter", "yoctoliters";
+
+        @barrel: 1.589_873_E-1; "bbl", "barrel", "barrels";
+        @bushel: 3.523_907_E-2; "bu", "bushel", "bushels";
+        @cubic_foot: 2.831_685_E-2; "ft³", "cubic foot", "cubic feet";
+        @cubic_inch: 1.638_706_E-5; "in³", "cubic inch", "cubic inches";
+        @cubic_yard: 7.645_549_E-1; "yd³", "cubic yard", "cubic yards";
+        @fluid_ounce: 2.957_353_E-5; "fl oz", "fluid ounce", "fluid ounces";
+        @fluid_ounce_imperial: 2.841_306_E-5; "fl oz (UK)", "Imperial fluid ounce",
+            "Imperial fluid ounces";
+        @gallon: 3.785_412_E-3; "gal", "gallon", "gallons";
+        @gallon_imperial: 4.546_09_E-3; "gal (UK)", "Imperial gallon", "Imperial gallons";
+        @pint_imperial: 5.682_613_E-4; "pt (UK)", "Imperial pint", "Imperial pints";
+        @pint_liquid: 4.731_765_E-4; "liq pt", "liquid pint", "liquid pints";
+        @quart_imperial: 1.136_523_E-3; "qt (UK)", "Imperial quart", "Imperial quarts";
+        @quart_liquid: 9.463_529_E-4; "liq qt", "liquid quart", "liquid quarts";
     }
 }
 
@@ -66,7 +102,6 @@ mod tests {
         use si::area as a;
         use si::volume as v;
         use si::length as l;
-        use tests::Test;
 
         #[test]
         fn check_dimension() {
@@ -109,12 +144,52 @@ mod tests {
                 test(l::yoctometer, v::cubic_yoctometer);
             }
 
-            // TODO #17 Convert to == once PartialEq is implemented.
-            fn test<L: l::Conversion<V>, O: v::Conversion<V>>(_l: L, v: O) {
-                Test::assert_eq(&V::one(),
-                    &(Length::new::<L>(V::one())
+            fn test<L: l::Conversion<V>, O: v::Conversion<V>>(_l: L, _v: O) {
+                assert_eq!(Volume::new::<O>(V::one()),
+                    Length::new::<L>(V::one())
                         * Length::new::<L>(V::one())
-                        * Length::new::<L>(V::one())).get(v));
+                        * Length::new::<L>(V::one()));
+            }
+        }
+    }
+
+    mod float {
+        storage_types! {
+            types: Float;
+
+            use si::quantities::*;
+            use si::volume as v;
+            use tests::Test;
+
+            #[test]
+            fn check_units() {
+                Test::assert_approx_eq(&1.0, &Volume::new::<v::liter>(1.0).get(v::cubic_decimeter));
+                Test::assert_approx_eq(&1.0,
+                    &Volume::new::<v::milliliter>(1.0).get(v::cubic_centimeter));
+                Test::assert_approx_eq(&1.0, &Volume::new::<v::kiloliter>(1.0).get(v::cubic_meter));
+                Test::assert_approx_eq(&158.987_3, &Volume::new::<v::barrel>(1.0).get(v::liter));
+                Test::assert_approx_eq(&35.239_07, &Volume::new::<v::bushel>(1.0).get(v::liter));
+                Test::assert_approx_eq(&28.316_85,
+                    &Volume::new::<v::cubic_foot>(1.0).get(v::liter));
+                Test::assert_approx_eq(&16.387_06,
+                    &Volume::new::<v::cubic_inch>(1.0).get(v::milliliter));
+                Test::assert_approx_eq(&0.764_554_9,
+                    &Volume::new::<v::cubic_yard>(1.0).get(v::cubic_meter));
+                Test::assert_approx_eq(&29.573_53,
+                    &Volume::new::<v::fluid_ounce>(1.0).get(v::milliliter));
+                Test::assert_approx_eq(&28.413_06,
+                    &Volume::new::<v::fluid_ounce_imperial>(1.0).get(v::milliliter));
+                Test::assert_approx_eq(&3.785_412, &Volume::new::<v::gallon>(1.0).get(v::liter));
+                Test::assert_approx_eq(&4.546_09,
+                    &Volume::new::<v::gallon_imperial>(1.0).get(v::liter));
+                Test::assert_approx_eq(&568.261_3,
+                    &Volume::new::<v::pint_imperial>(1.0).get(v::milliliter));
+                Test::assert_approx_eq(&473.176_5,
+                    &Volume::new::<v::pint_liquid>(1.0).get(v::milliliter));
+                Test::assert_approx_eq(&1.136_523,
+                    &Volume::new::<v::quart_imperial>(1.0).get(v::liter));
+                Test::assert_approx_eq(&946.352_9,
+                    &Volume::new::<v::quart_liquid>(1.0).get(v::milliliter));
             }
         }
     }
@@ -0,0 +1,60 @@
+//! Types to support parsing [quantities](http://jcgm.bipm.org/vim/en/1.1.html) from strings.
+//!
+//! Each quantity implements `FromStr` for strings containing a value followed by a
+//! [measurement unit](http://jcgm.bipm.org/vim/en/1.9.html) separated by whitespace. The unit can
+//! be given as the unit abbreviation, singular, or plural description.
+//!
+#![cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust")]
+#![cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+//! # extern crate uom;
+//! # use uom::si::f32::*;
+//! # use uom::si::length::meter;
+//! # use uom::str::ParseQuantityError;
+//! # fn main() {
+//! let l: Length = "12.5 km".parse().unwrap();
+//!
+//! assert_eq!(12_500.0, l.get(meter));
+//! assert_eq!(Ok(Length::new::<meter>(3.0)), "3 meters".parse());
+//! assert_eq!(Err(ParseQuantityError::ValueParseError), "x m".parse::<Length>());
+//! assert_eq!(Err(ParseQuantityError::UnknownUnit), "3 x".parse::<Length>());
+//! assert_eq!(Err(ParseQuantityError::DifferentQuantity("time")), "250 ms".parse::<Length>());
+//! # }
+//! ```
+
+use lib::fmt;
+
+/// Error returned when parsing a quantity from a string fails.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ParseQuantityError {
+    /// The value could not be parsed by the underlying storage type.
+    ValueParseError,
+    /// The unit is not a measurement unit of any quantity in the system of units. Also returned
+    /// when no unit is given.
+    UnknownUnit,
+    /// The unit is a measurement unit of a different quantity. Contains the description of the
+    /// quantity the unit belongs to (e.g. `"time"`).
+    DifferentQuantity(&'static str),
+}
+
+impl fmt::Display for ParseQuantityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseQuantityError::ValueParseError => write!(f, "error parsing value"),
+            ParseQuantityError::UnknownUnit => write!(f, "unknown unit"),
+            ParseQuantityError::DifferentQuantity(quantity) => {
+                write!(f, "unit of a different quantity ({})", quantity)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl ::lib::error::Error for ParseQuantityError {
+    fn description(&self) -> &str {
+        match *self {
+            ParseQuantityError::ValueParseError => "error parsing value",
+            ParseQuantityError::UnknownUnit => "unknown unit",
+            ParseQuantityError::DifferentQuantity(_) => "unit of a different quantity",
+        }
+    }
+}
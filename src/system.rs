@@ -125,6 +125,10 @@ macro_rules! system {
         pub trait Dimension: Send + Sync {
             $(/// Quantity dimension.
             type $symbol: $crate::typenum::Integer;)+
+
+            /// [Kind](https://jcgm.bipm.org/vim/en/1.2.html) of the quantity. See
+            /// [`Kind`](../trait.Kind.html).
+            type Kind: ?Sized;
         }
 
         /// Marker trait to identify a [system of units][units] based on a set of [base units][base]
@@ -236,7 +240,7 @@ macro_rules! system {
         }
 
         // Type alias for dimensions where all exponents of the factors are the given value.
-        type DN<N> = Dimension<$($symbol = system!(@replace $symbol N)),+>;
+        type DN<N> = Dimension<$($symbol = system!(@replace $symbol N),)+ Kind = $crate::Kind>;
 
         /// Type alias for [dimension one][one] for which all the exponents of the factors
         /// corresponding to the [base quantities][base] are zero.
@@ -246,8 +250,13 @@ macro_rules! system {
         #[allow(dead_code)]
         pub type DimensionOne = DN<$crate::typenum::Z0>;
 
+        // Type alias for the dimension of plane angles, dimension one of the angle kind.
+        type DimensionAngle =
+            Dimension<$($symbol = $crate::typenum::Z0,)+ Kind = $crate::AngleKind>;
+
         $(#[$quantities_attr])*
-        pub type $quantities<$($symbol),+> = Dimension<$($symbol = $symbol),+>;
+        pub type $quantities<$($symbol,)+ K = $crate::Kind> =
+            Dimension<$($symbol = $symbol,)+ Kind = K>;
 
         $(#[$units_attr])*
         #[allow(unused_qualifications)]
@@ -267,7 +276,7 @@ macro_rules! system {
             use $crate::ConversionFactor;
 
             (v.into_conversion() $(* U::$name::conversion().powi(D::$symbol::to_i32()))+
-                    / N::conversion())
+                    / N::conversion() - N::constant($crate::ConstantOp::Sub))
                 .value()
         }
 
@@ -284,11 +293,136 @@ macro_rules! system {
             use $crate::Conversion;
             use $crate::ConversionFactor;
 
-            (v.into_conversion() * N::conversion()
+            ((v.into_conversion() + N::constant($crate::ConstantOp::Add)) * N::conversion()
                     / (V::conversion() $(* U::$name::conversion().powi(D::$symbol::to_i32()))+))
                 .value()
         }
 
+        /// Convert a value from base units to the given unit using the given rounding mode when the
+        /// converted value can't be represented exactly.
+        #[inline(always)]
+        fn from_base_rounded<D, U, V, N>(v: &V, mode: $crate::RoundingMode) -> V
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::Conversion<V> + $crate::lib::ops::Mul<V, Output = V>,
+            N: $crate::Conversion<V, T = V::T>,
+        {
+            use $crate::typenum::Integer;
+            use $crate::Conversion;
+            use $crate::ConversionFactor;
+
+            (v.into_conversion() $(* U::$name::conversion().powi(D::$symbol::to_i32()))+
+                    / N::conversion() - N::constant($crate::ConstantOp::Sub))
+                .value_rounded(mode)
+        }
+
+        /// Convert a value from the given unit to base units using the given rounding mode when the
+        /// converted value can't be represented exactly.
+        #[inline(always)]
+        fn to_base_rounded<D, U, V, N>(v: &V, mode: $crate::RoundingMode) -> V
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::Conversion<V> + $crate::lib::ops::Mul<V, Output = V>,
+            N: $crate::Conversion<V, T = V::T>,
+        {
+            use $crate::typenum::Integer;
+            use $crate::Conversion;
+            use $crate::ConversionFactor;
+
+            ((v.into_conversion() + N::constant($crate::ConstantOp::Add)) * N::conversion()
+                    / (V::conversion() $(* U::$name::conversion().powi(D::$symbol::to_i32()))+))
+                .value_rounded(mode)
+        }
+
+        /// Convert a value from base units to the given unit. Returns an error if the conversion
+        /// overflows or if the converted value can't be represented exactly.
+        #[inline(always)]
+        fn try_from_base<D, U, V, N>(v: &V) -> Result<V, $crate::ConversionError>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::Conversion<V> + $crate::lib::ops::Mul<V, Output = V>,
+            V::T: $crate::num::CheckedAdd + $crate::num::CheckedSub + $crate::num::CheckedMul
+                + $crate::num::CheckedDiv,
+            N: $crate::Conversion<V, T = V::T>,
+        {
+            use $crate::num::{CheckedDiv, CheckedMul, CheckedSub};
+            use $crate::typenum::Integer;
+            use $crate::ConversionFactor;
+
+            Some(v.into_conversion())
+                $(.and_then(|v| {
+                    checked_factor::<U::$name, V>(D::$symbol::to_i32())
+                        .and_then(|f| v.checked_mul(&f))
+                }))+
+                .and_then(|v| N::try_conversion().and_then(|n| v.checked_div(&n)))
+                .and_then(|v| {
+                    N::try_constant($crate::ConstantOp::Sub).and_then(|c| v.checked_sub(&c))
+                })
+                .ok_or($crate::ConversionError::Overflow)
+                .and_then(|v| v.try_value())
+        }
+
+        /// Convert a value from the given unit to base units. Returns an error if the conversion
+        /// overflows or if the converted value can't be represented exactly.
+        #[inline(always)]
+        fn try_to_base<D, U, V, N>(v: &V) -> Result<V, $crate::ConversionError>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::Conversion<V> + $crate::lib::ops::Mul<V, Output = V>,
+            V::T: $crate::num::CheckedAdd + $crate::num::CheckedSub + $crate::num::CheckedMul
+                + $crate::num::CheckedDiv,
+            N: $crate::Conversion<V, T = V::T>,
+        {
+            use $crate::num::{CheckedAdd, CheckedDiv, CheckedMul};
+            use $crate::typenum::Integer;
+            use $crate::ConversionFactor;
+
+            let f = Some(V::conversion())
+                $(.and_then(|f| {
+                    checked_factor::<U::$name, V>(D::$symbol::to_i32())
+                        .and_then(|u| f.checked_mul(&u))
+                }))+;
+
+            N::try_constant($crate::ConstantOp::Add)
+                .and_then(|c| v.into_conversion().checked_add(&c))
+                .and_then(|v| N::try_conversion().and_then(|n| v.checked_mul(&n)))
+                .and_then(|v| f.and_then(|f| v.checked_div(&f)))
+                .ok_or($crate::ConversionError::Overflow)
+                .and_then(|v| v.try_value())
+        }
+
+        /// Conversion factor of the given unit raised to an integer power. Returns `None` if the
+        /// conversion factor is out of range or if the operation overflows.
+        #[inline(always)]
+        fn checked_factor<N, V>(e: i32) -> Option<V::T>
+        where
+            N: $crate::Conversion<V, T = V::T> + ?Sized,
+            V: $crate::Conversion<V>,
+            V::T: $crate::num::CheckedMul + $crate::num::CheckedDiv,
+        {
+            use $crate::num::{CheckedDiv, CheckedMul};
+
+            if 0 == e {
+                return Some(<V::T as $crate::num::One>::one());
+            }
+
+            N::try_conversion().and_then(|f| {
+                (0..e.abs()).fold(Some(<V::T as $crate::num::One>::one()), |p, _| {
+                    p.and_then(|p| {
+                        if e > 0 {
+                            p.checked_mul(&f)
+                        } else {
+                            p.checked_div(&f)
+                        }
+                    })
+                })
+            })
+        }
+
         /// Convert a value from one set of base units to a second.
         #[inline(always)]
         fn change_base<D, Ul, Ur, V>(v: &V) -> V
@@ -307,6 +441,161 @@ macro_rules! system {
                 .value()
         }
 
+        /// Conversion factor from one set of base units to a second. Returns `None` if a unit's
+        /// conversion factor is out of range or if the operation overflows.
+        #[inline(always)]
+        fn checked_change_base_factor<D, Ul, Ur, V>() -> Option<V::T>
+        where
+            D: Dimension + ?Sized,
+            Ul: Units<V> + ?Sized,
+            Ur: Units<V> + ?Sized,
+            V: $crate::Conversion<V>,
+            V::T: $crate::num::CheckedMul + $crate::num::CheckedDiv,
+        {
+            use $crate::num::{CheckedDiv, CheckedMul};
+            use $crate::typenum::Integer;
+
+            Some(<V::T as $crate::num::One>::one())
+                $(.and_then(|f| {
+                    checked_factor::<Ur::$name, V>(D::$symbol::to_i32())
+                        .and_then(|r| f.checked_mul(&r))
+                }).and_then(|f| {
+                    checked_factor::<Ul::$name, V>(D::$symbol::to_i32())
+                        .and_then(|l| f.checked_div(&l))
+                }))+
+        }
+
+        /// Convert a primitive integer value from one set of base units to a second. Returns `None`
+        /// if the conversion factor is out of range or if the conversion overflows.
+        #[inline(always)]
+        fn checked_change_base<D, Ul, Ur, V>(v: &V) -> Option<V>
+        where
+            D: Dimension + ?Sized,
+            Ul: Units<V> + ?Sized,
+            Ur: Units<V> + ?Sized,
+            V: $crate::num::PrimInt + $crate::num::Integer
+                + $crate::Conversion<V, T = $crate::num::rational::Ratio<V>>,
+            $crate::num::rational::Ratio<V>: $crate::ConversionFactor<V>,
+        {
+            checked_change_base_factor::<D, Ul, Ur, V>()
+                .and_then(|f| v.checked_mul(f.numer()).map(|v| v / *f.denom()))
+        }
+
+        /// Convert a primitive integer value from one set of base units to a second. Saturates at
+        /// the numeric bounds of the storage type if the conversion factor is out of range or if
+        /// the conversion overflows.
+        #[inline(always)]
+        fn saturating_change_base<D, Ul, Ur, V>(v: &V) -> V
+        where
+            D: Dimension + ?Sized,
+            Ul: Units<V> + ?Sized,
+            Ur: Units<V> + ?Sized,
+            V: $crate::num::PrimInt + $crate::num::Integer
+                + $crate::Conversion<V, T = $crate::num::rational::Ratio<V>>,
+            $crate::num::rational::Ratio<V>: $crate::ConversionFactor<V>,
+        {
+            checked_change_base::<D, Ul, Ur, V>(v).unwrap_or_else(|| {
+                if *v == V::zero() {
+                    V::zero()
+                } else if *v < V::zero() {
+                    V::min_value()
+                } else {
+                    V::max_value()
+                }
+            })
+        }
+
+        /// Convert a primitive integer value from one set of base units to a second. Wraps around
+        /// at the numeric bounds of the storage type if the conversion overflows.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the conversion factor is out of range for the storage type.
+        #[inline(always)]
+        fn wrapping_change_base<D, Ul, Ur, V>(v: &V) -> V
+        where
+            D: Dimension + ?Sized,
+            Ul: Units<V> + ?Sized,
+            Ur: Units<V> + ?Sized,
+            V: $crate::num::PrimInt + $crate::num::Integer
+                + $crate::Conversion<V, T = $crate::num::rational::Ratio<V>>
+                + $crate::num::traits::WrappingMul,
+            $crate::num::rational::Ratio<V>: $crate::ConversionFactor<V>,
+        {
+            let f = checked_change_base_factor::<D, Ul, Ur, V>()
+                .expect("conversion factor out of range for the underlying storage type");
+
+            v.wrapping_mul(f.numer()) / *f.denom()
+        }
+
+        /// Description of the quantity with a measurement unit matching the given abbreviation,
+        /// singular, or plural description.
+        #[allow(dead_code)]
+        #[inline]
+        fn unit_quantity(unit: &str) -> Option<&'static str> {
+            $(if $module::is_unit(unit) {
+                return Some($module::description());
+            })+
+
+            None
+        }
+
+        /// Measurement unit along with the quantity it belongs to. Returned by `find_unit`.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        pub struct QuantityUnit {
+            /// Description of the quantity.
+            pub description: &'static str,
+
+            /// Exponents of the base quantities in the quantity's dimension. See `DynQuantity`.
+            pub dimension: [i32; BASE_QUANTITIES],
+
+            /// Measurement unit.
+            pub unit: $crate::UnitDescriptor,
+        }
+
+        /// Finds the measurement unit with the given abbreviation. Abbreviations are not unique
+        /// across quantities (e.g. `K` is used for both thermodynamic temperature and temperature
+        /// interval) so the first match is returned. Base quantities are searched first, in the
+        /// order they are declared by the system of quantities, followed by all quantities in the
+        /// order they are declared by the system of units. `K` finds the thermodynamic temperature
+        /// unit in the SI.
+        #[allow(dead_code)]
+        pub fn find_unit(abbreviation: &str) -> Option<QuantityUnit> {
+            $(let unit = $name::units().iter().find(|u| u.abbreviation == abbreviation);
+
+            if let Some(unit) = unit {
+                return Some(QuantityUnit {
+                    description: $name::description(),
+                    dimension: exponents::<$name::Dimension>(),
+                    unit: *unit,
+                });
+            })+
+
+            $(let unit = $module::units().iter().find(|u| u.abbreviation == abbreviation);
+
+            if let Some(unit) = unit {
+                return Some(QuantityUnit {
+                    description: $module::description(),
+                    dimension: exponents::<$module::Dimension>(),
+                    unit: *unit,
+                });
+            })+
+
+            None
+        }
+
+        /// Exponents of the base quantities in the given dimension.
+        #[allow(dead_code)]
+        #[inline(always)]
+        fn exponents<D>() -> [i32; BASE_QUANTITIES]
+        where
+            D: Dimension + ?Sized,
+        {
+            use $crate::typenum::Integer;
+
+            [$(D::$symbol::to_i32()),+]
+        }
+
         impl<D, U, V> $crate::lib::clone::Clone for Quantity<D, U, V>
         where
             D: Dimension + ?Sized,
@@ -368,6 +657,74 @@ macro_rules! system {
             }
         }
 
+        impl<D, Ul, Ur, V> $crate::lib::cmp::PartialEq<Quantity<D, Ur, V>> for Quantity<D, Ul, V>
+        where
+            D: Dimension + ?Sized,
+            Ul: Units<V> + ?Sized,
+            Ur: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V>,
+        {
+            #[inline(always)]
+            fn eq(&self, other: &Quantity<D, Ur, V>) -> bool {
+                self.value == change_base::<D, Ul, Ur, V>(&other.value)
+            }
+        }
+
+        impl<D, U, V> $crate::lib::cmp::Eq for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::cmp::Eq,
+        {
+        }
+
+        impl<D, Ul, Ur, V> $crate::lib::cmp::PartialOrd<Quantity<D, Ur, V>> for Quantity<D, Ul, V>
+        where
+            D: Dimension + ?Sized,
+            Ul: Units<V> + ?Sized,
+            Ur: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::cmp::PartialOrd,
+        {
+            #[inline(always)]
+            fn partial_cmp(
+                &self, other: &Quantity<D, Ur, V>
+            ) -> Option<$crate::lib::cmp::Ordering> {
+                self.value.partial_cmp(&change_base::<D, Ul, Ur, V>(&other.value))
+            }
+
+            #[inline(always)]
+            fn lt(&self, other: &Quantity<D, Ur, V>) -> bool {
+                self.value.lt(&change_base::<D, Ul, Ur, V>(&other.value))
+            }
+
+            #[inline(always)]
+            fn le(&self, other: &Quantity<D, Ur, V>) -> bool {
+                self.value.le(&change_base::<D, Ul, Ur, V>(&other.value))
+            }
+
+            #[inline(always)]
+            fn gt(&self, other: &Quantity<D, Ur, V>) -> bool {
+                self.value.gt(&change_base::<D, Ul, Ur, V>(&other.value))
+            }
+
+            #[inline(always)]
+            fn ge(&self, other: &Quantity<D, Ur, V>) -> bool {
+                self.value.ge(&change_base::<D, Ul, Ur, V>(&other.value))
+            }
+        }
+
+        impl<D, U, V> $crate::lib::cmp::Ord for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::cmp::Ord,
+        {
+            #[inline(always)]
+            fn cmp(&self, other: &Self) -> $crate::lib::cmp::Ordering {
+                self.value.cmp(&other.value)
+            }
+        }
+
         #[doc(hidden)]
         macro_rules! impl_ops {
             (
@@ -382,6 +739,7 @@ macro_rules! system {
                     for Quantity<D, Ul, V>
                 where
                     D: Dimension + ?Sized,
+                    D::Kind: $crate::marker::$AddSubTrait,
                     Ul: Units<V> + ?Sized,
                     Ur: Units<V> + ?Sized,
                     V: $crate::num::Num + $crate::Conversion<V>,
@@ -402,6 +760,7 @@ macro_rules! system {
                     for Quantity<D, Ul, V>
                 where
                     D: Dimension + ?Sized,
+                    D::Kind: $crate::marker::$AddSubAssignTrait,
                     Ul: Units<V> + ?Sized,
                     Ur: Units<V> + ?Sized,
                     V: $crate::num::Num + $crate::Conversion<V>
@@ -417,8 +776,12 @@ macro_rules! system {
                     for Quantity<Dl, Ul, V>
                 where
                     Dl: Dimension + ?Sized,
-                    $(Dl::$symbol: $crate::lib::ops::$AddSubTrait<Dr::$symbol>,)+
+                    Dl::Kind: $crate::marker::$MulDivTrait,
+                    $(Dl::$symbol: $crate::lib::ops::$AddSubTrait<Dr::$symbol>,
+                    <Dl::$symbol as $crate::lib::ops::$AddSubTrait<Dr::$symbol>>::Output:
+                        $crate::typenum::Integer,)+
                     Dr: Dimension + ?Sized,
+                    Dr::Kind: $crate::marker::$MulDivTrait,
                     Ul: Units<V> + ?Sized,
                     Ur: Units<V> + ?Sized,
                     V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::ops::$MulDivTrait<V>,
@@ -441,6 +804,7 @@ macro_rules! system {
                 impl<D, U, V> $crate::lib::ops::$MulDivTrait<V> for Quantity<D, U, V>
                 where
                     D: Dimension + ?Sized,
+                    D::Kind: $crate::marker::$MulDivTrait,
                     U: Units<V> + ?Sized,
                     V: $crate::num::Num + $crate::Conversion<V>,
                 {
@@ -459,6 +823,7 @@ macro_rules! system {
                 impl<D, U, V> $crate::lib::ops::$MulDivAssignTrait<V> for Quantity<D, U, V>
                 where
                     D: Dimension + ?Sized,
+                    D::Kind: $crate::marker::$MulDivAssignTrait,
                     U: Units<V> + ?Sized,
                     V: $crate::num::Num + $crate::Conversion<V>
                         + $crate::lib::ops::$MulDivAssignTrait<V>,
@@ -477,8 +842,11 @@ macro_rules! system {
                         impl<D, U> $crate::lib::ops::$MulDivTrait<Quantity<D, U, V>> for V
                         where
                             D: Dimension + ?Sized,
+                            D::Kind: $crate::marker::$MulDivTrait,
                             U: Units<V> + ?Sized,
-                            $($crate::typenum::Z0: $crate::lib::ops::$AddSubTrait<D::$symbol>,)+
+                            $($crate::typenum::Z0: $crate::lib::ops::$AddSubTrait<D::$symbol>,
+                            <$crate::typenum::Z0 as $crate::lib::ops::$AddSubTrait<D::$symbol>>
+                                ::Output: $crate::typenum::Integer,)+
                         {
                             type Output = Quantity<
                                 $quantities<
@@ -589,7 +957,9 @@ macro_rules! system {
                 $quantities<$($crate::typenum::PartialQuot<D::$symbol, $crate::typenum::P3>),+>,
                 U, V>
             where
-                $(D::$symbol: $crate::lib::ops::PartialDiv<$crate::typenum::P3>,)+
+                $(D::$symbol: $crate::lib::ops::PartialDiv<$crate::typenum::P3>,
+                <D::$symbol as $crate::lib::ops::PartialDiv<$crate::typenum::P3>>::Output:
+                    $crate::typenum::Integer,)+
                 V: $crate::num::Float,
             {
                 Quantity {
@@ -663,7 +1033,9 @@ macro_rules! system {
                 b: Quantity<$quantities<$($crate::typenum::Sum<D::$symbol, Da::$symbol>),+>, Ub, V>,
             ) -> Quantity<$quantities<$($crate::typenum::Sum<D::$symbol, Da::$symbol>),+>, U, V>
             where
-                $(D::$symbol: $crate::lib::ops::Add<Da::$symbol>,)+
+                $(D::$symbol: $crate::lib::ops::Add<Da::$symbol>,
+                <D::$symbol as $crate::lib::ops::Add<Da::$symbol>>::Output:
+                    $crate::typenum::Integer,)+
                 V: $crate::num::Float,
                 Da: Dimension + ?Sized,
                 Ua: Units<V> + ?Sized,
@@ -690,7 +1062,8 @@ macro_rules! system {
                 self
             ) -> Quantity<$quantities<$($crate::typenum::Negate<D::$symbol>),+>, U, V>
             where
-                $(D::$symbol: $crate::lib::ops::Neg,)+
+                $(D::$symbol: $crate::lib::ops::Neg,
+                <D::$symbol as $crate::lib::ops::Neg>::Output: $crate::typenum::Integer,)+
                 V: $crate::num::Float,
             {
                 Quantity {
@@ -713,7 +1086,8 @@ macro_rules! system {
                 self, e: E
             ) -> Quantity<$quantities<$($crate::typenum::Prod<D::$symbol, E>),+>, U, V>
             where
-                $(D::$symbol: $crate::lib::ops::Mul<E>,)+
+                $(D::$symbol: $crate::lib::ops::Mul<E>,
+                <D::$symbol as $crate::lib::ops::Mul<E>>::Output: $crate::typenum::Integer,)+
                 E: $crate::typenum::Integer,
                 V: $crate::typenum::Pow<E, Output = V> + $crate::Conversion<V>,
             {
@@ -750,7 +1124,9 @@ macro_rules! system {
                 $quantities<$($crate::typenum::PartialQuot<D::$symbol, $crate::typenum::P2>),+>,
                 U, V>
             where
-                $(D::$symbol: $crate::typenum::PartialDiv<$crate::typenum::P2>,)+
+                $(D::$symbol: $crate::typenum::PartialDiv<$crate::typenum::P2>,
+                <D::$symbol as $crate::typenum::PartialDiv<$crate::typenum::P2>>::Output:
+                    $crate::typenum::Integer,)+
                 V: $crate::num::Float,
             {
                 Quantity {
@@ -760,103 +1136,1259 @@ macro_rules! system {
                 }
             }
 
-            /// Returns the maximum of the two quantities.
+            /// Calculates the length of the hypotenuse of a right-angle triangle given the legs.
+            ///
+            #[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust")]
+            #[cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::length::meter;
+            /// let l = Length::new::<meter>(3.0).hypot(Length::new::<meter>(4.0));
+            /// ```
             #[inline(always)]
-            pub fn max(self, other: Self) -> Self
+            pub fn hypot(self, other: Self) -> Self
             where
                 V: $crate::num::Float,
             {
                 Quantity {
                     dimension: $crate::lib::marker::PhantomData,
                     units: $crate::lib::marker::PhantomData,
-                    value: self.value.max(other.value),
+                    value: self.value.hypot(other.value),
                 }
             }
 
-            /// Returns the minimum of the two quantities.
+            /// Computes the value of the sine of the angle.
             #[inline(always)]
-            pub fn min(self, other: Self) -> Self
+            pub fn sin(self) -> Quantity<DimensionOne, U, V>
             where
+                D: Dimension<$($symbol = $crate::typenum::Z0,)+ Kind = $crate::AngleKind>,
                 V: $crate::num::Float,
             {
                 Quantity {
                     dimension: $crate::lib::marker::PhantomData,
                     units: $crate::lib::marker::PhantomData,
-                    value: self.value.min(other.value),
+                    value: self.value.sin(),
                 }
             }
-        }
-
-        impl<D, U, V> $crate::lib::ops::Neg for Quantity<D, U, V>
-        where
-            D: Dimension + ?Sized,
-            U: Units<V> + ?Sized,
-            V: $crate::num::Signed + $crate::Conversion<V>,
-        {
-            type Output = Quantity<D, U, V>;
 
+            /// Computes the value of the cosine of the angle.
             #[inline(always)]
-            fn neg(self) -> Self::Output {
+            pub fn cos(self) -> Quantity<DimensionOne, U, V>
+            where
+                D: Dimension<$($symbol = $crate::typenum::Z0,)+ Kind = $crate::AngleKind>,
+                V: $crate::num::Float,
+            {
                 Quantity {
                     dimension: $crate::lib::marker::PhantomData,
                     units: $crate::lib::marker::PhantomData,
-                    value: -self.value,
+                    value: self.value.cos(),
                 }
             }
-        }
-
-        impl<D, Ul, Ur, V> $crate::lib::ops::Rem<Quantity<D, Ur, V>>
-            for Quantity<D, Ul, V>
-        where
-            D: Dimension + ?Sized,
-            Ul: Units<V> + ?Sized,
-            Ur: Units<V> + ?Sized,
-            V: $crate::num::Num + $crate::Conversion<V>,
-        {
-            type Output = Quantity<D, Ul, V>;
 
+            /// Computes the value of the tangent of the angle.
             #[inline(always)]
-            fn rem(self, rhs: Quantity<D, Ur, V>) -> Self::Output {
+            pub fn tan(self) -> Quantity<DimensionOne, U, V>
+            where
+                D: Dimension<$($symbol = $crate::typenum::Z0,)+ Kind = $crate::AngleKind>,
+                V: $crate::num::Float,
+            {
                 Quantity {
                     dimension: $crate::lib::marker::PhantomData,
                     units: $crate::lib::marker::PhantomData,
-                    value: self.value % change_base::<D, Ul, Ur, V>(&rhs.value)
+                    value: self.value.tan(),
                 }
             }
-        }
 
-        impl<D, Ul, Ur, V> $crate::lib::ops::RemAssign<Quantity<D, Ur, V>>
-            for Quantity<D, Ul, V>
-        where
-            D: Dimension + ?Sized,
-            Ul: Units<V> + ?Sized,
-            Ur: Units<V> + ?Sized,
-            V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::ops::RemAssign,
-        {
+            /// Simultaneously computes the sine and cosine of the angle. Returns `(sin(self),
+            /// cos(self))`.
             #[inline(always)]
-            fn rem_assign(&mut self, rhs: Quantity<D, Ur, V>) {
-                self.value %= change_base::<D, Ul, Ur, V>(&rhs.value)
+            pub fn sin_cos(self) -> (Quantity<DimensionOne, U, V>, Quantity<DimensionOne, U, V>)
+            where
+                D: Dimension<$($symbol = $crate::typenum::Z0,)+ Kind = $crate::AngleKind>,
+                V: $crate::num::Float,
+            {
+                let (sin, cos) = self.value.sin_cos();
+
+                (
+                    Quantity {
+                        dimension: $crate::lib::marker::PhantomData,
+                        units: $crate::lib::marker::PhantomData,
+                        value: sin,
+                    },
+                    Quantity {
+                        dimension: $crate::lib::marker::PhantomData,
+                        units: $crate::lib::marker::PhantomData,
+                        value: cos,
+                    },
+                )
             }
-        }
 
-        impl<D, U, V> $crate::num::Zero for Quantity<D, U, V>
-        where
-            D: Dimension + ?Sized,
-            U: Units<V> + ?Sized,
-            V: $crate::num::Num + $crate::Conversion<V>,
-        {
-            fn zero() -> Self {
+            /// Computes the value of the inverse sine of the ratio. The angle is in the range
+            /// [-π/2, π/2] radians or `NAN` if the ratio is outside the range [-1, 1].
+            #[inline(always)]
+            pub fn asin(self) -> Quantity<DimensionAngle, U, V>
+            where
+                D: Dimension<$($symbol = $crate::typenum::Z0,)+ Kind = $crate::Kind>,
+                V: $crate::num::Float,
+            {
                 Quantity {
                     dimension: $crate::lib::marker::PhantomData,
                     units: $crate::lib::marker::PhantomData,
-                    value: V::zero(),
+                    value: self.value.asin(),
                 }
             }
 
-            fn is_zero(&self) -> bool {
-                self.value.is_zero()
+            /// Computes the value of the inverse cosine of the ratio. The angle is in the range
+            /// [0, π] radians or `NAN` if the ratio is outside the range [-1, 1].
+            #[inline(always)]
+            pub fn acos(self) -> Quantity<DimensionAngle, U, V>
+            where
+                D: Dimension<$($symbol = $crate::typenum::Z0,)+ Kind = $crate::Kind>,
+                V: $crate::num::Float,
+            {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: self.value.acos(),
+                }
             }
-        }
+
+            /// Computes the value of the inverse tangent of the ratio. The angle is in the range
+            /// [-π/2, π/2] radians.
+            #[inline(always)]
+            pub fn atan(self) -> Quantity<DimensionAngle, U, V>
+            where
+                D: Dimension<$($symbol = $crate::typenum::Z0,)+ Kind = $crate::Kind>,
+                V: $crate::num::Float,
+            {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: self.value.atan(),
+                }
+            }
+
+            /// Computes the four quadrant arctangent of `self` (y) and `other` (x). Both
+            /// quantities must have the same dimension.
+            ///
+            #[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust")]
+            #[cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::angle::degree;
+            /// # use uom::si::length::meter;
+            /// let a: Angle = Length::new::<meter>(1.0).atan2(Length::new::<meter>(1.0));
+            ///
+            /// assert!((a.get(degree) - 45.0).abs() < 1.0E-4);
+            /// ```
+            #[inline(always)]
+            pub fn atan2(self, other: Self) -> Quantity<DimensionAngle, U, V>
+            where
+                V: $crate::num::Float,
+            {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: self.value.atan2(other.value),
+                }
+            }
+
+            /// Returns the maximum of the two quantities.
+            #[inline(always)]
+            pub fn max(self, other: Self) -> Self
+            where
+                V: $crate::num::Float,
+            {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: self.value.max(other.value),
+                }
+            }
+
+            /// Returns the minimum of the two quantities.
+            #[inline(always)]
+            pub fn min(self, other: Self) -> Self
+            where
+                V: $crate::num::Float,
+            {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: self.value.min(other.value),
+                }
+            }
+        }
+
+        #[doc(hidden)]
+        macro_rules! impl_int_ops {
+            (
+                $AddSubTrait:ident, $addsub_fun:ident, $AddSubAlias:ident,
+                $checked_addsub_fun:ident, $saturating_addsub_fun:ident,
+                $WrappingAddSubTrait:ident, $wrapping_addsub_fun:ident,
+                $MulDivTrait:ident, $muldiv_fun:ident,
+                $checked_muldiv_fun:ident, $saturating_muldiv_fun:ident,
+                $wrapping_muldiv_fun:ident
+            ) => {
+                impl<D, U, V> Quantity<D, U, V>
+                where
+                    D: Dimension + ?Sized,
+                    U: Units<V> + ?Sized,
+                    V: $crate::num::PrimInt + $crate::num::Integer
+                        + $crate::Conversion<V, T = $crate::num::rational::Ratio<V>>,
+                    $crate::num::rational::Ratio<V>: $crate::ConversionFactor<V>,
+                {
+                    /// Checked operation. Returns `None` if overflow occurred, including overflow
+                    /// or an out of range conversion factor while converting `rhs` to the base
+                    /// units of `self`.
+                    #[inline(always)]
+                    pub fn $checked_addsub_fun<Ur>(self, rhs: Quantity<D, Ur, V>) -> Option<Self>
+                    where
+                        D::Kind: $crate::marker::$AddSubTrait,
+                        Ur: Units<V> + ?Sized,
+                    {
+                        checked_change_base::<D, U, Ur, V>(&rhs.value)
+                            .and_then(|rhs| self.value.$checked_addsub_fun(&rhs))
+                            .map(|value| Quantity {
+                                dimension: $crate::lib::marker::PhantomData,
+                                units: $crate::lib::marker::PhantomData,
+                                value,
+                            })
+                    }
+
+                    /// Saturating operation. Saturates at the numeric bounds of the storage type
+                    /// instead of overflowing, including while converting `rhs` to the base units
+                    /// of `self`.
+                    #[inline(always)]
+                    pub fn $saturating_addsub_fun<Ur>(self, rhs: Quantity<D, Ur, V>) -> Self
+                    where
+                        D::Kind: $crate::marker::$AddSubTrait,
+                        Ur: Units<V> + ?Sized,
+                    {
+                        Quantity {
+                            dimension: $crate::lib::marker::PhantomData,
+                            units: $crate::lib::marker::PhantomData,
+                            value: self.value.$saturating_addsub_fun(
+                                saturating_change_base::<D, U, Ur, V>(&rhs.value)),
+                        }
+                    }
+
+                    /// Wrapping operation. Wraps around at the numeric bounds of the storage type
+                    /// instead of overflowing, including while converting `rhs` to the base units
+                    /// of `self`.
+                    ///
+                    /// # Panics
+                    ///
+                    /// Panics if the conversion factor between the base units of `rhs` and `self`
+                    /// is out of range for the storage type.
+                    #[inline(always)]
+                    pub fn $wrapping_addsub_fun<Ur>(self, rhs: Quantity<D, Ur, V>) -> Self
+                    where
+                        D::Kind: $crate::marker::$AddSubTrait,
+                        Ur: Units<V> + ?Sized,
+                        V: $crate::num::traits::$WrappingAddSubTrait
+                            + $crate::num::traits::WrappingMul,
+                    {
+                        Quantity {
+                            dimension: $crate::lib::marker::PhantomData,
+                            units: $crate::lib::marker::PhantomData,
+                            value: self.value.$wrapping_addsub_fun(
+                                &wrapping_change_base::<D, U, Ur, V>(&rhs.value)),
+                        }
+                    }
+
+                    /// Checked operation. Returns `None` if overflow or division by zero occurred,
+                    /// including overflow or an out of range conversion factor while converting
+                    /// `rhs` to the base units of `self`.
+                    #[inline(always)]
+                    pub fn $checked_muldiv_fun<Dr, Ur>(
+                        self,
+                        rhs: Quantity<Dr, Ur, V>,
+                    ) -> Option<Quantity<
+                        $quantities<$($crate::typenum::$AddSubAlias<D::$symbol, Dr::$symbol>,)+>,
+                        U, V>>
+                    where
+                        D::Kind: $crate::marker::$MulDivTrait,
+                        $(D::$symbol: $crate::lib::ops::$AddSubTrait<Dr::$symbol>,
+                        <D::$symbol as $crate::lib::ops::$AddSubTrait<Dr::$symbol>>::Output:
+                            $crate::typenum::Integer,)+
+                        Dr: Dimension + ?Sized,
+                        Dr::Kind: $crate::marker::$MulDivTrait,
+                        Ur: Units<V> + ?Sized,
+                    {
+                        checked_change_base::<Dr, U, Ur, V>(&rhs.value)
+                            .and_then(|rhs| self.value.$checked_muldiv_fun(&rhs))
+                            .map(|value| Quantity {
+                                dimension: $crate::lib::marker::PhantomData,
+                                units: $crate::lib::marker::PhantomData,
+                                value,
+                            })
+                    }
+
+                    /// Saturating operation. Saturates at the numeric bounds of the storage type
+                    /// instead of overflowing, including while converting `rhs` to the base units
+                    /// of `self`.
+                    ///
+                    /// # Panics
+                    ///
+                    /// Panics if dividing by zero.
+                    #[inline(always)]
+                    pub fn $saturating_muldiv_fun<Dr, Ur>(
+                        self,
+                        rhs: Quantity<Dr, Ur, V>,
+                    ) -> Quantity<
+                        $quantities<$($crate::typenum::$AddSubAlias<D::$symbol, Dr::$symbol>,)+>,
+                        U, V>
+                    where
+                        D::Kind: $crate::marker::$MulDivTrait,
+                        $(D::$symbol: $crate::lib::ops::$AddSubTrait<Dr::$symbol>,
+                        <D::$symbol as $crate::lib::ops::$AddSubTrait<Dr::$symbol>>::Output:
+                            $crate::typenum::Integer,)+
+                        Dr: Dimension + ?Sized,
+                        Dr::Kind: $crate::marker::$MulDivTrait,
+                        Ur: Units<V> + ?Sized,
+                    {
+                        let rhs = saturating_change_base::<Dr, U, Ur, V>(&rhs.value);
+                        let value = self.value.$checked_muldiv_fun(&rhs).unwrap_or_else(|| {
+                            if rhs == V::zero() {
+                                self.value.$muldiv_fun(rhs)
+                            } else if (self.value < V::zero()) != (rhs < V::zero()) {
+                                V::min_value()
+                            } else {
+                                V::max_value()
+                            }
+                        });
+
+                        Quantity {
+                            dimension: $crate::lib::marker::PhantomData,
+                            units: $crate::lib::marker::PhantomData,
+                            value,
+                        }
+                    }
+
+                    /// Wrapping operation. Wraps around at the numeric bounds of the storage type
+                    /// instead of overflowing, including while converting `rhs` to the base units
+                    /// of `self`.
+                    ///
+                    /// # Panics
+                    ///
+                    /// Panics if dividing by zero or if the conversion factor between the base
+                    /// units of `rhs` and `self` is out of range for the storage type.
+                    #[inline(always)]
+                    pub fn $wrapping_muldiv_fun<Dr, Ur>(
+                        self,
+                        rhs: Quantity<Dr, Ur, V>,
+                    ) -> Quantity<
+                        $quantities<$($crate::typenum::$AddSubAlias<D::$symbol, Dr::$symbol>,)+>,
+                        U, V>
+                    where
+                        D::Kind: $crate::marker::$MulDivTrait,
+                        $(D::$symbol: $crate::lib::ops::$AddSubTrait<Dr::$symbol>,
+                        <D::$symbol as $crate::lib::ops::$AddSubTrait<Dr::$symbol>>::Output:
+                            $crate::typenum::Integer,)+
+                        Dr: Dimension + ?Sized,
+                        Dr::Kind: $crate::marker::$MulDivTrait,
+                        Ur: Units<V> + ?Sized,
+                        V: $crate::num::traits::WrappingMul,
+                    {
+                        let rhs = wrapping_change_base::<Dr, U, Ur, V>(&rhs.value);
+
+                        Quantity {
+                            dimension: $crate::lib::marker::PhantomData,
+                            units: $crate::lib::marker::PhantomData,
+                            value: impl_int_ops!(@$wrapping_muldiv_fun self.value, rhs),
+                        }
+                    }
+                }
+            };
+            (@wrapping_mul $l:expr, $r:expr) => {
+                $crate::num::traits::WrappingMul::wrapping_mul(&$l, &$r)
+            };
+            (@wrapping_div $l:expr, $r:expr) => {
+                // Only `MIN / -1` overflows and wraps around to `MIN`.
+                $l.checked_div(&$r).unwrap_or_else(|| if $r == V::zero() { $l / $r } else { $l })
+            };
+        }
+
+        impl_int_ops!(Add, add, Sum, checked_add, saturating_add, WrappingAdd, wrapping_add,
+            Mul, mul, checked_mul, saturating_mul, wrapping_mul);
+        impl_int_ops!(Sub, sub, Diff, checked_sub, saturating_sub, WrappingSub, wrapping_sub,
+            Div, div, checked_div, saturating_div, wrapping_div);
+
+        impl<D, U, V> $crate::lib::ops::Neg for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: $crate::marker::Neg,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Signed + $crate::Conversion<V>,
+        {
+            type Output = Quantity<D, U, V>;
+
+            #[inline(always)]
+            fn neg(self) -> Self::Output {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: -self.value,
+                }
+            }
+        }
+
+        impl<D, Ul, Ur, V> $crate::lib::ops::Rem<Quantity<D, Ur, V>>
+            for Quantity<D, Ul, V>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: $crate::marker::Rem,
+            Ul: Units<V> + ?Sized,
+            Ur: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V>,
+        {
+            type Output = Quantity<D, Ul, V>;
+
+            #[inline(always)]
+            fn rem(self, rhs: Quantity<D, Ur, V>) -> Self::Output {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: self.value % change_base::<D, Ul, Ur, V>(&rhs.value)
+                }
+            }
+        }
+
+        impl<D, Ul, Ur, V> $crate::lib::ops::RemAssign<Quantity<D, Ur, V>>
+            for Quantity<D, Ul, V>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: $crate::marker::RemAssign,
+            Ul: Units<V> + ?Sized,
+            Ur: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::ops::RemAssign,
+        {
+            #[inline(always)]
+            fn rem_assign(&mut self, rhs: Quantity<D, Ur, V>) {
+                self.value %= change_base::<D, Ul, Ur, V>(&rhs.value)
+            }
+        }
+
+        impl<D, U, V> $crate::num::Zero for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: $crate::marker::Add,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V>,
+        {
+            fn zero() -> Self {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: V::zero(),
+                }
+            }
+
+            fn is_zero(&self) -> bool {
+                self.value.is_zero()
+            }
+        }
+
+        impl<D, U, V> $crate::lib::iter::Sum for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: $crate::marker::Add,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V>,
+        {
+            #[inline(always)]
+            fn sum<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = Self>,
+            {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: iter.fold(V::zero(), |s, q| s + q.value),
+                }
+            }
+        }
+
+        impl<'a, D, U, V> $crate::lib::iter::Sum<&'a Quantity<D, U, V>> for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            D::Kind: $crate::marker::Add,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::lib::clone::Clone,
+        {
+            #[inline(always)]
+            fn sum<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = &'a Quantity<D, U, V>>,
+            {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: iter.fold(V::zero(), |s, q| s + q.value.clone()),
+                }
+            }
+        }
+
+        impl<D, U, V> Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V>,
+        {
+            /// Converts the quantity into a quantity expressed in a different set of base units.
+            /// The value is converted using the same conversion as operations between quantities
+            /// with different base units. `From` isn't implemented for this conversion because
+            /// it would conflict with the reflexive `From<T> for T` implementation.
+            #[inline(always)]
+            pub fn into_units<U2>(self) -> Quantity<D, U2, V>
+            where
+                U2: Units<V> + ?Sized,
+            {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: change_base::<D, U2, U, V>(&self.value),
+                }
+            }
+
+            /// Multiplies the quantities yielded by the iterator. The dimension of the product is
+            /// the dimension of `Self` raised to the power `E`. Returns `None` when the iterator
+            /// doesn't yield exactly `E` quantities.
+            ///
+            #[cfg_attr(all(feature = "si", feature = "f32"), doc = " ```rust")]
+            #[cfg_attr(not(all(feature = "si", feature = "f32")), doc = " ```rust,ignore")]
+            /// # use uom::si::f32::*;
+            /// # use uom::si::length::meter;
+            /// # use uom::si::volume::cubic_meter;
+            /// let l = [2.0, 3.0, 4.0].iter().map(|v| Length::new::<meter>(*v));
+            /// let v: Option<Volume> = Length::product(::uom::typenum::P3::new(), l);
+            ///
+            /// assert_eq!(Some(24.0), v.map(|v| v.get(cubic_meter)));
+            /// ```
+            #[inline(always)]
+            pub fn product<E, I>(
+                _e: E, iter: I
+            ) -> Option<Quantity<$quantities<$($crate::typenum::Prod<D::$symbol, E>),+>, U, V>>
+            where
+                D::Kind: $crate::marker::Mul,
+                $(D::$symbol: $crate::lib::ops::Mul<E>,
+                <D::$symbol as $crate::lib::ops::Mul<E>>::Output: $crate::typenum::Integer,)+
+                E: $crate::typenum::Integer,
+                I: $crate::lib::iter::IntoIterator<Item = Self>,
+            {
+                let mut n = 0;
+                let value = iter.into_iter().fold(V::one(), |p, q| {
+                    n += 1;
+
+                    p * q.value
+                });
+
+                if n == E::to_i32() {
+                    Some(Quantity {
+                        dimension: $crate::lib::marker::PhantomData,
+                        units: $crate::lib::marker::PhantomData,
+                        value,
+                    })
+                } else {
+                    None
+                }
+            }
+        }
+
+        #[doc(hidden)]
+        mod cast {
+            storage_types! {
+                types: PrimInt, Float, BigInt, BigUint;
+
+                use super::super::*;
+
+                impl<D, U> Quantity<D, U, V>
+                where
+                    D: Dimension + ?Sized,
+                    U: Units<V> + ?Sized,
+                {
+                    /// Converts the quantity into a quantity with a different primitive storage
+                    /// type using `NumCast`. Returns `None` when the value can't be represented by
+                    /// the new storage type, e.g. when it is out of range or not a number.
+                    /// Fractional values are truncated when converting to an integer storage type.
+                    ///
+                    /// Lossless conversions (e.g. `f32` to `f64`, `u8` to `i32`, or `i64` to
+                    /// `BigInt`) are also available using `From` and `Into`.
+                    #[inline(always)]
+                    pub fn try_cast<V2>(
+                        self
+                    ) -> Option<Quantity<D, Units<V2, $($name = <U as Units<V>>::$name),+>, V2>>
+                    where
+                        V2: $crate::num::Num + $crate::Conversion<V2> + $crate::num::NumCast,
+                        $(<U as Units<V>>::$name:
+                            $crate::Conversion<V2, T = <V2 as $crate::Conversion<V2>>::T>,)+
+                    {
+                        $crate::num::NumCast::from(self.value).map(|value| Quantity {
+                            dimension: $crate::lib::marker::PhantomData,
+                            units: $crate::lib::marker::PhantomData,
+                            value,
+                        })
+                    }
+                }
+            }
+
+            storage_types! {
+                types: Ratio;
+
+                use super::super::*;
+
+                impl<D, U> Quantity<D, U, V>
+                where
+                    D: Dimension + ?Sized,
+                    U: Units<V> + ?Sized,
+                {
+                    /// Converts the quantity into a quantity with a different primitive storage
+                    /// type. Returns `None` when the value can't be represented by the new storage
+                    /// type. The integer and fractional parts of the ratio are converted
+                    /// separately so that only the value itself, and not its numerator and
+                    /// denominator, needs to be in range. Fractional values are truncated when
+                    /// converting to an integer storage type.
+                    #[inline(always)]
+                    pub fn try_cast<V2>(
+                        self
+                    ) -> Option<Quantity<D, Units<V2, $($name = <U as Units<V>>::$name),+>, V2>>
+                    where
+                        V2: $crate::num::Num + $crate::Conversion<V2> + $crate::num::NumCast,
+                        $(<U as Units<V>>::$name:
+                            $crate::Conversion<V2, T = <V2 as $crate::Conversion<V2>>::T>,)+
+                    {
+                        let fract = self.value.fract();
+                        let integer: Option<V2> =
+                            $crate::num::NumCast::from(self.value.to_integer());
+                        let fract: Option<V2> = $crate::num::ToPrimitive::to_f64(fract.numer())
+                            .and_then(|n| {
+                                $crate::num::ToPrimitive::to_f64(fract.denom()).map(|d| n / d)
+                            })
+                            .and_then($crate::num::NumCast::from);
+
+                        integer.and_then(|i| fract.map(|f| Quantity {
+                            dimension: $crate::lib::marker::PhantomData,
+                            units: $crate::lib::marker::PhantomData,
+                            value: i + f,
+                        }))
+                    }
+                }
+            }
+        }
+
+        #[doc(hidden)]
+        mod from {
+            system!(@from ($($name),+) as u8: u16, u32, u64, usize, i16, i32, i64, isize, f32, f64);
+            system!(@from ($($name),+) as u16: u32, u64, usize, i32, i64, f32, f64);
+            system!(@from ($($name),+) as u32: u64, i64, f64);
+            system!(@from ($($name),+) as i8: i16, i32, i64, isize, f32, f64);
+            system!(@from ($($name),+) as i16: i32, i64, isize, f32, f64);
+            system!(@from ($($name),+) as i32: i64, f64);
+            system!(@from ($($name),+) as f32: f64);
+        }
+
+        #[doc(hidden)]
+        mod into {
+            system!(@from ($($name),+) into u8: BigInt, BigUint);
+            system!(@from ($($name),+) into u16: BigInt, BigUint);
+            system!(@from ($($name),+) into u32: BigInt, BigUint);
+            system!(@from ($($name),+) into u64: BigInt, BigUint);
+            system!(@from ($($name),+) into usize: BigInt, BigUint);
+            system!(@from ($($name),+) into i8: BigInt);
+            system!(@from ($($name),+) into i16: BigInt);
+            system!(@from ($($name),+) into i32: BigInt, Rational32);
+            system!(@from ($($name),+) into i64: BigInt, Rational64);
+            system!(@from ($($name),+) into isize: BigInt, Rational);
+            system!(@from ($($name),+) into BigUint: BigInt);
+            system!(@from ($($name),+) into BigInt: BigRational);
+        }
+
+        #[cfg(feature = "serde")]
+        impl<D, U, V> $crate::serde::Serialize for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::serde_support::SerdeValue,
+        {
+            #[inline(always)]
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: $crate::serde::Serializer,
+            {
+                $crate::serde_support::SerdeValue::serialize(&self.value, serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, D, U, V> $crate::serde::Deserialize<'de> for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::serde_support::SerdeValue,
+        {
+            #[inline(always)]
+            fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+            where
+                De: $crate::serde::Deserializer<'de>,
+            {
+                let value: V = $crate::serde_support::SerdeValue::deserialize(deserializer)?;
+
+                Ok(Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value,
+                })
+            }
+        }
+
+        #[cfg(feature = "approx")]
+        impl<D, Ul, Ur, V> $crate::approx::AbsDiffEq<Quantity<D, Ur, V>> for Quantity<D, Ul, V>
+        where
+            D: Dimension + ?Sized,
+            Ul: Units<V> + ?Sized,
+            Ur: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::approx::AbsDiffEq<Epsilon = V>,
+        {
+            type Epsilon = Self;
+
+            #[inline(always)]
+            fn default_epsilon() -> Self::Epsilon {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: V::default_epsilon(),
+                }
+            }
+
+            #[inline(always)]
+            fn abs_diff_eq(&self, other: &Quantity<D, Ur, V>, epsilon: Self::Epsilon) -> bool {
+                self.value.abs_diff_eq(&change_base::<D, Ul, Ur, V>(&other.value), epsilon.value)
+            }
+        }
+
+        /// `approx` requires `max_relative` to be of the `Epsilon` type so it is given as a quantity
+        /// even though it is a dimensionless ratio. Only the quantity's value in base units is used
+        /// as the ratio, e.g. `Length::new::<meter>(0.01)` allows a relative difference of 1 % while
+        /// `Length::new::<kilometer>(0.01)` allows a relative difference of 1 000 %.
+        #[cfg(feature = "approx")]
+        impl<D, Ul, Ur, V> $crate::approx::RelativeEq<Quantity<D, Ur, V>> for Quantity<D, Ul, V>
+        where
+            D: Dimension + ?Sized,
+            Ul: Units<V> + ?Sized,
+            Ur: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::approx::RelativeEq<Epsilon = V>,
+        {
+            #[inline(always)]
+            fn default_max_relative() -> Self::Epsilon {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: V::default_max_relative(),
+                }
+            }
+
+            #[inline(always)]
+            fn relative_eq(
+                &self,
+                other: &Quantity<D, Ur, V>,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                self.value.relative_eq(
+                    &change_base::<D, Ul, Ur, V>(&other.value), epsilon.value, max_relative.value)
+            }
+        }
+
+        #[cfg(feature = "approx")]
+        impl<D, Ul, Ur, V> $crate::approx::UlpsEq<Quantity<D, Ur, V>> for Quantity<D, Ul, V>
+        where
+            D: Dimension + ?Sized,
+            Ul: Units<V> + ?Sized,
+            Ur: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::approx::UlpsEq<Epsilon = V>,
+        {
+            #[inline(always)]
+            fn default_max_ulps() -> u32 {
+                V::default_max_ulps()
+            }
+
+            #[inline(always)]
+            fn ulps_eq(
+                &self,
+                other: &Quantity<D, Ur, V>,
+                epsilon: Self::Epsilon,
+                max_ulps: u32,
+            ) -> bool {
+                self.value.ulps_eq(
+                    &change_base::<D, Ul, Ur, V>(&other.value), epsilon.value, max_ulps)
+            }
+        }
+
+        #[cfg(feature = "testing")]
+        impl<D, U, V> $crate::quickcheck::Arbitrary for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized + 'static,
+            U: Units<V> + ?Sized + 'static,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::quickcheck::Arbitrary,
+        {
+            fn arbitrary<G>(g: &mut G) -> Self
+            where
+                G: $crate::quickcheck::Gen,
+            {
+                Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: V::arbitrary(g),
+                }
+            }
+
+            fn shrink(&self) -> Box<Iterator<Item = Self>> {
+                Box::new(self.value.shrink().map(|v| Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: v,
+                }))
+            }
+        }
+
+        #[cfg(feature = "testing")]
+        impl<D, U, V> $crate::testing::FromBase for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V> + $crate::num::FromPrimitive,
+        {
+            #[inline(always)]
+            fn from_base(value: f64) -> Option<Self> {
+                V::from_f64(value).map(|v| Quantity {
+                    dimension: $crate::lib::marker::PhantomData,
+                    units: $crate::lib::marker::PhantomData,
+                    value: v,
+                })
+            }
+        }
+
+        /// Number of base quantities in the system of quantities.
+        const BASE_QUANTITIES: usize = system!(@count $($name)+);
+
+        /// [Quantity][quantity] with a [dimension][dimension] that is only known at runtime.
+        ///
+        /// The dimension is stored as the exponents of the base quantities in the order they are
+        /// declared by the system of quantities and the value is stored in the base units of the
+        /// system of units. Addition and subtraction check that the dimensions of both operands
+        /// match and return a `Result`. Multiplication and division of two `DynQuantity`s combine
+        /// the dimensions of the operands and return a `Result` that is an error when an exponent
+        /// overflows `i32`.
+        ///
+        /// A `DynQuantity` is created from a statically typed `Quantity` using `From`.
+        /// `try_into_quantity` converts back after checking the dimension. The
+        /// [kind](../trait.Kind.html) of a quantity isn't tracked at runtime.
+        ///
+        /// [quantity]: http://jcgm.bipm.org/vim/en/1.1.html
+        /// [dimension]: http://jcgm.bipm.org/vim/en/1.7.html
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        pub struct DynQuantity<V> {
+            /// Exponents of the base quantities.
+            pub dimension: [i32; BASE_QUANTITIES],
+
+            /// Value in the base units of the system of units.
+            pub value: V,
+        }
+
+        impl<V> DynQuantity<V>
+        where
+            V: $crate::num::Num + $crate::Conversion<V>,
+        {
+            /// Converts into a statically typed quantity. Returns `DimensionError` when the
+            /// dimension of the quantity doesn't match `D`.
+            #[inline(always)]
+            pub fn try_into_quantity<D, U>(
+                self,
+            ) -> Result<Quantity<D, U, V>, $crate::DimensionError>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                $($name::$unit: $crate::Conversion<V, T = V::T>,)+
+            {
+                if self.dimension == exponents::<D>() {
+                    Ok(Quantity {
+                        dimension: $crate::lib::marker::PhantomData,
+                        units: $crate::lib::marker::PhantomData,
+                        value: change_base::<D, U, Units<V, $($name = $name::$unit),+>, V>(
+                            &self.value),
+                    })
+                } else {
+                    Err($crate::DimensionError)
+                }
+            }
+        }
+
+        impl<D, U, V> $crate::lib::convert::From<Quantity<D, U, V>> for DynQuantity<V>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V>,
+            $($name::$unit: $crate::Conversion<V, T = V::T>,)+
+        {
+            #[inline(always)]
+            fn from(val: Quantity<D, U, V>) -> Self {
+                DynQuantity {
+                    dimension: exponents::<D>(),
+                    value: change_base::<D, Units<V, $($name = $name::$unit),+>, U, V>(
+                        &val.value),
+                }
+            }
+        }
+
+        #[cfg(feature = "try_from")]
+        impl<D, U, V> $crate::lib::convert::TryFrom<DynQuantity<V>> for Quantity<D, U, V>
+        where
+            D: Dimension + ?Sized,
+            U: Units<V> + ?Sized,
+            V: $crate::num::Num + $crate::Conversion<V>,
+            $($name::$unit: $crate::Conversion<V, T = V::T>,)+
+        {
+            type Error = $crate::DimensionError;
+
+            #[inline(always)]
+            fn try_from(val: DynQuantity<V>) -> Result<Self, Self::Error> {
+                val.try_into_quantity()
+            }
+        }
+
+        impl<V> $crate::lib::cmp::PartialOrd for DynQuantity<V>
+        where
+            V: $crate::lib::cmp::PartialOrd,
+        {
+            /// Compares the values of quantities with the same dimension. Returns `None` when the
+            /// dimensions don't match.
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> Option<$crate::lib::cmp::Ordering> {
+                if self.dimension == other.dimension {
+                    self.value.partial_cmp(&other.value)
+                } else {
+                    None
+                }
+            }
+        }
+
+        #[doc(hidden)]
+        macro_rules! impl_dyn_ops {
+            (
+                $AddSubTrait:ident, $addsub_fun:ident, $addsub_op:tt, $checked_addsub_fun:ident,
+                $MulDivTrait:ident, $muldiv_fun:ident, $muldiv_op:tt
+            ) => {
+                impl<V> $crate::lib::ops::$AddSubTrait for DynQuantity<V>
+                where
+                    V: $crate::num::Num,
+                {
+                    type Output = Result<DynQuantity<V>, $crate::DimensionError>;
+
+                    #[inline(always)]
+                    fn $addsub_fun(self, rhs: DynQuantity<V>) -> Self::Output {
+                        if self.dimension == rhs.dimension {
+                            Ok(DynQuantity {
+                                dimension: self.dimension,
+                                value: self.value $addsub_op rhs.value,
+                            })
+                        } else {
+                            Err($crate::DimensionError)
+                        }
+                    }
+                }
+
+                impl<V> $crate::lib::ops::$MulDivTrait for DynQuantity<V>
+                where
+                    V: $crate::num::Num,
+                {
+                    type Output = Result<DynQuantity<V>, $crate::DimensionError>;
+
+                    #[inline(always)]
+                    fn $muldiv_fun(self, rhs: DynQuantity<V>) -> Self::Output {
+                        let mut dimension = self.dimension;
+
+                        for (l, r) in dimension.iter_mut().zip(rhs.dimension.iter()) {
+                            *l = l.$checked_addsub_fun(*r).ok_or($crate::DimensionError)?;
+                        }
+
+                        Ok(DynQuantity {
+                            dimension,
+                            value: self.value $muldiv_op rhs.value,
+                        })
+                    }
+                }
+
+                impl<V> $crate::lib::ops::$MulDivTrait<V> for DynQuantity<V>
+                where
+                    V: $crate::num::Num,
+                {
+                    type Output = DynQuantity<V>;
+
+                    #[inline(always)]
+                    fn $muldiv_fun(self, rhs: V) -> Self::Output {
+                        DynQuantity {
+                            dimension: self.dimension,
+                            value: self.value $muldiv_op rhs,
+                        }
+                    }
+                }
+            };
+        }
+
+        impl_dyn_ops!(Add, add, +, checked_add, Mul, mul, *);
+        impl_dyn_ops!(Sub, sub, -, checked_sub, Div, div, /);
+
+        impl<V> $crate::lib::ops::Neg for DynQuantity<V>
+        where
+            V: $crate::num::Signed,
+        {
+            type Output = DynQuantity<V>;
+
+            #[inline(always)]
+            fn neg(self) -> Self::Output {
+                DynQuantity {
+                    dimension: self.dimension,
+                    value: -self.value,
+                }
+            }
+        }
+
+        /// Types to support formatting of quantities in a given measurement unit. See the
+        /// `format_args` and `into_format_args` methods of each quantity.
+        pub mod fmt {
+            use $crate::lib::fmt;
+            use $crate::lib::marker::PhantomData;
+            use $crate::fmt::{DisplayStyle, UnitSelection};
+            use super::{change_base, from_base, Dimension, Quantity, Unit, Units};
+
+            /// Arguments to format a quantity in the measurement unit `N` using the given
+            /// [`DisplayStyle`](../../fmt/enum.DisplayStyle.html). Combine with a quantity using
+            /// [`with`](#method.with) to format multiple quantities using the same arguments.
+            pub struct Arguments<D, N>
+            where
+                D: Dimension + ?Sized,
+                N: Unit,
+            {
+                pub(super) dimension: PhantomData<D>,
+                pub(super) unit: N,
+                pub(super) style: DisplayStyle,
+            }
+
+            /// A quantity along with the arguments to format it. Implements
+            /// [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html). Formatting
+            /// flags (width, precision, sign, ...) are applied to the value; the unit follows the
+            /// value separated by a space.
+            pub struct QuantityArguments<D, U, V, N>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V>,
+                N: Unit,
+            {
+                pub(super) arguments: Arguments<D, N>,
+                pub(super) quantity: Quantity<D, U, V>,
+            }
+
+            /// A quantity along with the arguments to format it in a measurement unit selected
+            /// automatically. Implements
+            /// [`Display`](https://doc.rust-lang.org/std/fmt/trait.Display.html). See
+            /// [`UnitSelection`](../../fmt/enum.UnitSelection.html). The unit is selected after
+            /// rounding the value to the formatting precision and falls back to the quantity's base
+            /// unit when no unit can be selected.
+            pub struct AutoQuantityArguments<D, U, V>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V>,
+            {
+                pub(super) quantity: Quantity<D, U, V>,
+                pub(super) units: &'static [$crate::UnitDescriptor],
+                pub(super) style: DisplayStyle,
+                pub(super) selection: UnitSelection,
+            }
+
+            impl<D, N> Arguments<D, N>
+            where
+                D: Dimension + ?Sized,
+                N: Unit,
+            {
+                /// Combine the format arguments with the given quantity.
+                #[inline(always)]
+                pub fn with<U, V>(
+                    self,
+                    quantity: Quantity<D, U, V>,
+                ) -> QuantityArguments<D, U, V, N>
+                where
+                    U: Units<V> + ?Sized,
+                    V: $crate::num::Num + $crate::Conversion<V>,
+                {
+                    QuantityArguments {
+                        arguments: self,
+                        quantity,
+                    }
+                }
+            }
+
+            impl<D, N> $crate::lib::clone::Clone for Arguments<D, N>
+            where
+                D: Dimension + ?Sized,
+                N: Unit,
+            {
+                #[inline(always)]
+                fn clone(&self) -> Self {
+                    Arguments {
+                        dimension: PhantomData,
+                        unit: self.unit,
+                        style: self.style,
+                    }
+                }
+            }
+
+            impl<D, N> $crate::lib::marker::Copy for Arguments<D, N>
+            where
+                D: Dimension + ?Sized,
+                N: Unit,
+            {
+            }
+
+            impl<D, N> fmt::Debug for Arguments<D, N>
+            where
+                D: Dimension + ?Sized,
+                N: Unit,
+            {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "Arguments {{ unit: {}, style: {:?} }}",
+                        N::abbreviation(), self.style)
+                }
+            }
+
+            impl<D, U, V, N> fmt::Debug for QuantityArguments<D, U, V, N>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + fmt::Debug,
+                N: Unit,
+            {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "QuantityArguments {{ arguments: {:?}, quantity: {:?} }}",
+                        self.arguments, self.quantity)
+                }
+            }
+
+            impl<D, U, V, N> fmt::Display for QuantityArguments<D, U, V, N>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + fmt::Display,
+                N: Unit + $crate::Conversion<V, T = V::T>,
+            {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    let value = from_base::<D, U, V, N>(&self.quantity.value);
+
+                    value.fmt(f)?;
+
+                    match self.arguments.style {
+                        DisplayStyle::Abbreviation => write!(f, " {}", N::abbreviation()),
+                        DisplayStyle::Description if V::one() == value => {
+                            write!(f, " {}", N::singular())
+                        }
+                        DisplayStyle::Description => write!(f, " {}", N::plural()),
+                    }
+                }
+            }
+
+            impl<D, U, V> fmt::Debug for AutoQuantityArguments<D, U, V>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: $crate::num::Num + $crate::Conversion<V> + fmt::Debug,
+            {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f,
+                        "AutoQuantityArguments {{ quantity: {:?}, style: {:?}, selection: {:?} }}",
+                        self.quantity, self.style, self.selection)
+                }
+            }
+
+            impl<D, U, V> fmt::Display for AutoQuantityArguments<D, U, V>
+            where
+                D: Dimension + ?Sized,
+                U: Units<V> + ?Sized,
+                V: $crate::num::Float + $crate::Conversion<V> + fmt::Display,
+                $(super::$name::$unit: $crate::Conversion<V, T = V::T>,)+
+            {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    use $crate::num::NumCast;
+
+                    let base = change_base::<D, Units<V, $($name = super::$name::$unit),+>, U, V>(
+                        &self.quantity.value);
+                    let factors = |unit: &$crate::UnitDescriptor| {
+                        match (<V as NumCast>::from(unit.conversion()),
+                            <V as NumCast>::from(unit.constant()))
+                        {
+                            (Some(factor), Some(constant)) => Some((factor, constant)),
+                            _ => None,
+                        }
+                    };
+                    let select = |b: V| {
+                        b.to_f64()
+                            .and_then(|b| $crate::fmt::select_unit(self.units, b, self.selection))
+                    };
+                    // Fall back to the base unit when no unit can be selected.
+                    let mut unit = select(base).or_else(|| {
+                        self.units.iter().find(|u| 1.0 == u.conversion() && 0.0 == u.constant())
+                    });
+
+                    // Rounding to the formatting precision can carry the value into a larger unit
+                    // (e.g. `999.999 m` formatted with `{:.2}` is `1.00 km`).
+                    if let Some(precision) = f.precision() {
+                        let scale = <V as NumCast>::from(10.0).map(|s| s.powi(precision as i32));
+
+                        for _ in self.units {
+                            let carry = match (unit, unit.and_then(&factors), scale) {
+                                (Some(u), Some((factor, constant)), Some(scale)) => {
+                                    let rounded = ((base / factor - constant) * scale).round()
+                                        / scale;
+
+                                    if rounded.is_finite() {
+                                        select((rounded + constant) * factor)
+                                            .and_then(|c| {
+                                                if c.conversion() > u.conversion() {
+                                                    Some(c)
+                                                } else {
+                                                    None
+                                                }
+                                            })
+                                    } else {
+                                        None
+                                    }
+                                }
+                                _ => None,
+                            };
+
+                            match carry {
+                                Some(_) => unit = carry,
+                                None => break,
+                            }
+                        }
+                    }
+
+                    let (unit, value) = match unit.and_then(|u| factors(u).map(|c| (u, c))) {
+                        Some((unit, (factor, constant))) => (unit, base / factor - constant),
+                        None => return base.fmt(f),
+                    };
+
+                    value.fmt(f)?;
+
+                    match self.style {
+                        DisplayStyle::Abbreviation => write!(f, " {}", unit.abbreviation),
+                        DisplayStyle::Description if V::one() == value => {
+                            write!(f, " {}", unit.singular)
+                        }
+                        DisplayStyle::Description => write!(f, " {}", unit.plural),
+                    }
+                }
+            }
+        }
 
         /// Macro to implement [`quantity`](si/struct.Quantity.html) type aliases for a specific
         /// [system of units][units] and value storage type.
@@ -977,5 +2509,38 @@ macro_rules! system {
         #[allow(unused_qualifications)]
         pub type $quantity = system::$module::$quantity<Units, $V>;)+
     };
+    (@from ($($name:ident),+) $conv:tt $V:ident: $($V2:ident),+) => {
+        storage_types! {
+            types: $V;
+
+            storage_types! {
+                types: $($V2),+;
+
+                use super::super::super::*;
+
+                impl<D, U> $crate::lib::convert::From<Quantity<D, U, super::V>>
+                    for Quantity<D, Units<V, $($name = <U as Units<super::V>>::$name),+>, V>
+                where
+                    D: Dimension + ?Sized,
+                    U: Units<super::V> + ?Sized,
+                    $(<U as Units<super::V>>::$name:
+                        $crate::Conversion<V, T = <V as $crate::Conversion<V>>::T>,)+
+                {
+                    #[inline(always)]
+                    fn from(val: Quantity<D, U, super::V>) -> Self {
+                        Quantity {
+                            dimension: $crate::lib::marker::PhantomData,
+                            units: $crate::lib::marker::PhantomData,
+                            value: system!(@from_value $conv val.value),
+                        }
+                    }
+                }
+            }
+        }
+    };
+    (@from_value as $value:expr) => { $value as V };
+    (@from_value into $value:expr) => { $value.into() };
     (@replace $_t:tt $sub:ty) => { $sub };
+    (@count $_t:tt $($rest:tt)*) => { 1 + system!(@count $($rest)*) };
+    (@count) => { 0 };
 }
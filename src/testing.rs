@@ -0,0 +1,208 @@
+//! Utilities to test code using [quantities](http://jcgm.bipm.org/vim/en/1.1.html). Enabled by the
+//! `testing` feature.
+//!
+//! Every quantity implements [`quickcheck`](https://docs.rs/quickcheck)'s `Arbitrary` when the
+//! underlying storage type does. The generated value is in the base units of the quantity's system
+//! of units. [`InRange`](struct.InRange.html) limits the generated values to a
+//! [`Range`](trait.Range.html) (e.g. a physically sensible range for the quantity).
+//!
+//! `assert_quantity_approx_eq!` asserts that two quantities are approximately equal when compared
+//! in the given measurement unit. Floating point values are compared using an absolute difference
+//! and ULPs based comparison. Other storage types are compared exactly. Both quantities are
+//! formatted in the given unit when the assertion fails.
+//!
+#![cfg_attr(all(feature = "si", feature = "f64"), doc = " ```rust")]
+#![cfg_attr(not(all(feature = "si", feature = "f64")), doc = " ```rust,ignore")]
+//! # #[macro_use]
+//! # extern crate uom;
+//! # extern crate quickcheck;
+//! # use uom::si::f64::*;
+//! # use uom::si::length::{centimeter, kilometer, meter};
+//! # use uom::testing::{InRange, Range};
+//! # fn main() {
+//! #[derive(Clone, Debug)]
+//! struct Human;
+//!
+//! impl Range for Human {
+//!     fn min() -> f64 { 0.5 }
+//!     fn max() -> f64 { 2.5 }
+//! }
+//!
+//! fn height(l: InRange<Length, Human>) -> bool {
+//!     *l > Length::new::<centimeter>(50.0) && *l < Length::new::<meter>(2.5)
+//! }
+//!
+//! quickcheck::quickcheck(height as fn(InRange<Length, Human>) -> bool);
+//!
+//! assert_quantity_approx_eq!(Length::new::<meter>(1_000.0), Length::new::<kilometer>(1.0),
+//!     kilometer);
+//! # }
+//! ```
+
+use lib::fmt;
+use lib::marker::PhantomData;
+use lib::ops::Deref;
+use quickcheck::{Arbitrary, Gen};
+
+/// Range of values, in the base units of a quantity's system of units, used to generate arbitrary
+/// values for an [`InRange`](struct.InRange.html) quantity. `min` must be less than `max` and the
+/// range must contain values that the quantity's underlying storage type can represent.
+pub trait Range: Send + 'static {
+    /// Inclusive lower bound.
+    fn min() -> f64;
+
+    /// Exclusive upper bound.
+    fn max() -> f64;
+}
+
+/// Quantity with an arbitrary value limited to the range given by `R`. Dereferences to the
+/// quantity.
+///
+/// # Panics
+///
+/// Generating a value panics if `R::min()` isn't less than `R::max()` or if none of a bounded
+/// number of values generated in the range can be represented by the underlying storage type.
+pub struct InRange<Q, R> {
+    /// The quantity.
+    pub quantity: Q,
+    range: PhantomData<R>,
+}
+
+impl<Q, R> InRange<Q, R> {
+    /// Consume the wrapper and return the quantity.
+    #[inline(always)]
+    pub fn into_inner(self) -> Q {
+        self.quantity
+    }
+}
+
+impl<Q, R> Deref for InRange<Q, R> {
+    type Target = Q;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.quantity
+    }
+}
+
+impl<Q, R> Clone for InRange<Q, R>
+where
+    Q: Clone,
+{
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        InRange {
+            quantity: self.quantity.clone(),
+            range: PhantomData,
+        }
+    }
+}
+
+impl<Q, R> fmt::Debug for InRange<Q, R>
+where
+    Q: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.quantity.fmt(f)
+    }
+}
+
+impl<Q, R> Arbitrary for InRange<Q, R>
+where
+    Q: FromBase + Clone + Send + 'static,
+    R: Range,
+{
+    fn arbitrary<G>(g: &mut G) -> Self
+    where
+        G: Gen,
+    {
+        assert!(R::min() < R::max(), "`Range::min` must be less than `Range::max`");
+
+        for _ in 0..MAX_ATTEMPTS {
+            if let Some(quantity) = Q::from_base(g.gen_range(R::min(), R::max())) {
+                return InRange {
+                    quantity,
+                    range: PhantomData,
+                };
+            }
+        }
+
+        panic!("no value generated in the `Range` can be represented by the storage type")
+    }
+}
+
+/// Number of values generated for an [`InRange`](struct.InRange.html) quantity before giving up
+/// when none can be represented by the underlying storage type.
+const MAX_ATTEMPTS: usize = 100;
+
+/// Trait to create a quantity from a value in the base units of the quantity's system of units.
+/// Implemented by the [`system!`](../macro.system.html) macro.
+#[doc(hidden)]
+pub trait FromBase: Sized {
+    /// Create a quantity from the given value. Returns `None` if the underlying storage type
+    /// can't represent the value.
+    fn from_base(value: f64) -> Option<Self>;
+}
+
+/// Trait to approximately compare underlying storage type values. Floating point values are
+/// compared using an absolute difference and ULPs based comparison. Other storage types are
+/// compared exactly.
+pub trait ApproxEq {
+    /// Approximately compare `lhs` and `rhs` and return the result.
+    fn approx_eq(lhs: &Self, rhs: &Self) -> bool;
+}
+
+storage_types! {
+    types: Float;
+
+    use num::Float;
+
+    const ULPS: u32 = 16;
+
+    impl ::testing::ApproxEq for V {
+        #[inline(always)]
+        fn approx_eq(lhs: &Self, rhs: &Self) -> bool {
+            ::approx::UlpsEq::ulps_eq(lhs, rhs, 64.0 * V::epsilon(), ULPS)
+        }
+    }
+}
+
+storage_types! {
+    types: PrimInt, BigInt, BigUint, Ratio;
+
+    impl ::testing::ApproxEq for V {
+        #[inline(always)]
+        fn approx_eq(lhs: &Self, rhs: &Self) -> bool {
+            lhs == rhs
+        }
+    }
+}
+
+/// Asserts that two quantities are approximately equal when compared in the given measurement
+/// unit. See [`testing`](testing/index.html) for details. An optional message with format arguments
+/// can follow the unit.
+#[macro_export]
+macro_rules! assert_quantity_approx_eq {
+    (@assert $left:expr, $right:expr, $unit:expr, $fmt:tt $(, $args:expr)*) => {
+        match (&$left, &$right, $unit) {
+            (left, right, unit) => {
+                if !$crate::testing::ApproxEq::approx_eq(&left.get(unit), &right.get(unit)) {
+                    let style = $crate::fmt::DisplayStyle::Abbreviation;
+
+                    panic!(
+                        concat!("assertion failed: `(left ≈ right)`\n  left: `{}`,\n right: `{}`",
+                            $fmt),
+                        left.clone().into_format_args(unit, style),
+                        right.clone().into_format_args(unit, style)
+                        $(, $args)*)
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $unit:expr) => {
+        assert_quantity_approx_eq!(@assert $left, $right, $unit, "")
+    };
+    ($left:expr, $right:expr, $unit:expr, $($arg:tt)+) => {
+        assert_quantity_approx_eq!(@assert $left, $right, $unit, ": {}", format_args!($($arg)+))
+    };
+}
@@ -1,7 +1,11 @@
 use self::length::{kilometer, meter};
 use self::mass::kilogram;
 #[allow(unused_imports)]
-use {Conversion, ConversionFactor};
+use {Conversion, ConversionError, ConversionFactor, RoundingMode};
+#[allow(unused_imports)]
+use fmt::DisplayStyle;
+#[allow(unused_imports)]
+use str::ParseQuantityError;
 #[allow(unused_imports)]
 use num::{Float, FromPrimitive, One, Signed, Zero};
 use quickcheck::TestResult;
@@ -29,6 +33,8 @@ mod mass {
         dimension: Q<Z0, P1>;
         units {
             @kilogram: 1000.0 / 1000.0; "kg", "kilogram", "kilograms";
+            // Block expression as `$conversion` doesn't need to be a constant expression.
+            @gram: { let factor = 1.0E3; 1.0 / factor }; "g", "gram", "grams";
         }
     }
 }
@@ -102,6 +108,23 @@ mod test_trait {
 
         impl super::super::Test for V {}
     }
+
+    // Quantities are compared using their values in base units.
+    #[cfg(feature = "si")]
+    impl<D, U, V> super::Test for ::si::Quantity<D, U, V>
+    where
+        D: ::si::Dimension + ?Sized,
+        U: ::si::Units<V> + ?Sized,
+        V: super::Test + ::num::Num + ::Conversion<V>,
+    {
+        fn assert_approx_eq(lhs: &Self, rhs: &Self) {
+            super::Test::assert_approx_eq(&lhs.value, &rhs.value);
+        }
+
+        fn approx_eq(lhs: &Self, rhs: &Self) -> bool {
+            super::Test::approx_eq(&lhs.value, &rhs.value)
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -198,6 +221,76 @@ mod quantity_macro {
         assert_eq!("kilograms", kilogram::plural());
     }
 
+    #[test]
+    fn units() {
+        let units = length::units();
+
+        assert_eq!(2, units.len());
+        assert_eq!(("km", "kilometer", "kilometers"),
+            (units[0].abbreviation, units[0].singular, units[0].plural));
+        assert_eq!(1000.0, units[0].conversion());
+        assert_eq!(0.0, units[0].constant());
+        assert_eq!("m", units[1].abbreviation);
+        assert_eq!(1.0, units[1].conversion());
+        assert_eq!(2, mass::units().len());
+        assert_eq!("kg", mass::units()[0].abbreviation);
+        assert_eq!(1.0E-3, mass::units()[1].conversion());
+    }
+
+    #[test]
+    fn select_unit() {
+        use fmt::{select_unit, UnitSelection};
+        use UnitDescriptor;
+
+        macro_rules! unit {
+            ($abbreviation:expr, $conversion:expr, $constant:expr) => {
+                UnitDescriptor {
+                    abbreviation: $abbreviation,
+                    singular: "",
+                    plural: "",
+                    conversion: { fn conversion() -> f64 { $conversion } conversion },
+                    constant: { fn constant() -> f64 { $constant } constant },
+                }
+            };
+        }
+
+        static UNITS: &'static [UnitDescriptor] = &[
+            unit!("s", 1.0, 0.0),
+            unit!("µs", 1.0E-6, 0.0),
+            unit!("ks", 1.0E3, 0.0),
+            unit!("h", 3.6E3, 0.0),
+            unit!("cs", 1.0E-2, 0.0),
+            unit!("o", 1.0E3, 1.0),
+        ];
+        let s = |v, selection| select_unit(UNITS, v, selection).map(|u| u.abbreviation);
+
+        assert_eq!(Some("s"), s(1.0, UnitSelection::Prefixed));
+        assert_eq!(Some("µs"), s(0.5, UnitSelection::Prefixed));
+        assert_eq!(Some("µs"), s(3.2E-6, UnitSelection::Prefixed));
+        assert_eq!(Some("µs"), s(1.0E-9, UnitSelection::Prefixed));
+        assert_eq!(Some("ks"), s(7.2E3, UnitSelection::Prefixed));
+        assert_eq!(Some("ks"), s(-7.2E3, UnitSelection::Prefixed));
+        assert_eq!(Some("s"), s(0.0, UnitSelection::Prefixed));
+        assert_eq!(Some("s"), s(::lib::f64::NAN, UnitSelection::Prefixed));
+        assert_eq!(Some("h"), s(7.2E3, UnitSelection::All));
+        assert_eq!(Some("cs"), s(0.5, UnitSelection::All));
+        assert_eq!(None, select_unit(&UNITS[5..], 1.0, UnitSelection::All));
+    }
+
+    #[test]
+    fn find_unit() {
+        let km = ::tests::find_unit("km").unwrap();
+        let kg = ::tests::find_unit("kg").unwrap();
+
+        assert_eq!("length", km.description);
+        assert_eq!([1, 0], km.dimension);
+        assert_eq!(length::units()[0], km.unit);
+        assert_eq!("mass", kg.description);
+        assert_eq!([0, 1], kg.dimension);
+        assert_eq!(None, ::tests::find_unit("kilometer"));
+        assert_eq!(None, ::tests::find_unit("s"));
+    }
+
     storage_types! {
         use tests::*;
 
@@ -260,6 +353,86 @@ mod quantity_macro {
                 format!("{:?} m^1 kg^1", V::from_f64(1.23).unwrap()),
                 format!("{:?}", Length::new::<meter>(V::from_f64(1.23).unwrap()) * Mass::new::<kilogram>(V::one())));
         }
+
+        #[cfg(feature = "std")]
+        #[test]
+        fn display_fmt() {
+            let l1 = Length::new::<meter>(V::one());
+            let l2 = Length::new::<kilometer>(V::one());
+            let a = Length::format_args(meter, DisplayStyle::Description);
+
+            assert_eq!(
+                format!("{} m", V::one()),
+                format!("{}", l1.clone().into_format_args(meter, DisplayStyle::Abbreviation)));
+            assert_eq!(
+                format!("{} km", V::one()),
+                format!("{}", l2.clone().into_format_args(kilometer, DisplayStyle::Abbreviation)));
+            assert_eq!(
+                format!("{} meter", V::one()),
+                format!("{}", l1.clone().into_format_args(meter, DisplayStyle::Description)));
+            assert_eq!(
+                format!("{} meters", V::from_f64(1000.0).unwrap()),
+                format!("{}", l2.clone().into_format_args(meter, DisplayStyle::Description)));
+            assert_eq!(
+                format!("{:>8} m", V::one()),
+                format!("{:>8}", l1.clone().into_format_args(meter, DisplayStyle::Abbreviation)));
+            assert_eq!(
+                format!("{:.2} kilometer", V::one()),
+                format!("{:.2}", l2.clone().into_format_args(kilometer, DisplayStyle::Description)));
+            assert_eq!(format!("{} meter", V::one()), format!("{}", a.with(l1)));
+            assert_eq!(
+                format!("{} meters", V::from_f64(1000.0).unwrap()),
+                format!("{}", a.with(l2)));
+        }
+
+        #[test]
+        fn from_str() {
+            let l1 = Length::new::<meter>(V::one());
+            let l2 = Length::new::<kilometer>(V::one());
+            let m1 = Mass::new::<kilogram>(V::one());
+
+            assert_eq!(Ok(l1.clone()), "1 m".parse::<Length>());
+            assert_eq!(Ok(l1.clone()), "1 meter".parse::<Length>());
+            assert_eq!(Ok(l1.clone()), " 1   meters ".parse::<Length>());
+            assert_eq!(Ok(l2.clone()), "1 km".parse::<Length>());
+            assert_eq!(Ok(l2.clone()), "1\tkilometer".parse::<Length>());
+            assert_eq!(Ok(m1.clone()), "1 kg".parse::<Mass>());
+            assert_eq!(Err(ParseQuantityError::ValueParseError), "a m".parse::<Length>());
+            assert_eq!(Err(ParseQuantityError::ValueParseError), "1m".parse::<Length>());
+            assert_eq!(Err(ParseQuantityError::UnknownUnit), "1".parse::<Length>());
+            assert_eq!(Err(ParseQuantityError::UnknownUnit), "1 ft".parse::<Length>());
+            assert_eq!(Err(ParseQuantityError::UnknownUnit), "1 M".parse::<Length>());
+            assert_eq!(
+                Err(ParseQuantityError::DifferentQuantity("mass")),
+                "1 kg".parse::<Length>());
+            assert_eq!(
+                Err(ParseQuantityError::DifferentQuantity("length")),
+                "1 meters".parse::<Mass>());
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde() {
+            let l = Length::new::<kilometer>(V::one());
+            let t = length::Tagged::new(l.clone(), kilometer);
+            let v = ::serde_json::to_string(&::serde_support::SerializeValue(&V::one())).unwrap();
+
+            assert_eq!(l, ::serde_json::from_str::<Length>(
+                &::serde_json::to_string(&l).unwrap()).unwrap());
+            assert_eq!(
+                format!(r#"{{"value":{},"unit":"km"}}"#, v),
+                ::serde_json::to_string(&t).unwrap());
+
+            let t = ::serde_json::from_str::<length::Tagged<U<V>, V>>(
+                &format!(r#"{{"unit":"kilometers","value":{}}}"#, v)).unwrap();
+
+            assert_eq!("km", t.unit());
+            assert_eq!(l, t.into_inner());
+            assert!(::serde_json::from_str::<length::Tagged<U<V>, V>>(
+                &format!(r#"{{"value":{},"unit":"kg"}}"#, v)).is_err());
+            assert!(::serde_json::from_str::<length::Tagged<U<V>, V>>(
+                &format!(r#"{{"value":{}}}"#, v)).is_err());
+        }
     }
 
     mod float {
@@ -267,9 +440,34 @@ mod quantity_macro {
             types: Float;
 
             use tests::*;
+            use fmt::UnitSelection;
 
             Q!(tests, V);
 
+            #[test]
+            fn auto_format_args() {
+                let f = |v, style| format!("{}", Length::new::<meter>(v)
+                    .into_auto_format_args(style, UnitSelection::Prefixed));
+
+                assert_eq!("1.5 km", f(1_500.0, DisplayStyle::Abbreviation));
+                assert_eq!("-2 km", f(-2_000.0, DisplayStyle::Abbreviation));
+                assert_eq!("999 m", f(999.0, DisplayStyle::Abbreviation));
+                assert_eq!("0.5 m", f(0.5, DisplayStyle::Abbreviation));
+                assert_eq!("0 m", f(0.0, DisplayStyle::Abbreviation));
+                assert_eq!("1 kilometer", f(1_000.0, DisplayStyle::Description));
+                assert_eq!("2.5 meters", f(2.5, DisplayStyle::Description));
+                assert_eq!("1.23 km", format!("{:.2}", Length::new::<meter>(1_234.5)
+                    .into_auto_format_args(DisplayStyle::Abbreviation, UnitSelection::All)));
+                assert_eq!("1 kg", format!("{}", Mass::new::<kilogram>(1.0)
+                    .into_auto_format_args(DisplayStyle::Abbreviation, UnitSelection::All)));
+                assert_eq!("1.00 km", format!("{:.2}", Length::new::<meter>(999.999)
+                    .into_auto_format_args(DisplayStyle::Abbreviation, UnitSelection::All)));
+                assert_eq!("999.99 m", format!("{:.2}", Length::new::<meter>(999.99)
+                    .into_auto_format_args(DisplayStyle::Abbreviation, UnitSelection::All)));
+                assert_eq!("-1 km", format!("{:.0}", Length::new::<meter>(-999.6)
+                    .into_auto_format_args(DisplayStyle::Abbreviation, UnitSelection::All)));
+            }
+
             #[test]
             fn floor() {
                 let l1 = Length::new::<kilometer>(3.9999);
@@ -374,6 +572,34 @@ mod quantity_macro {
 
                 Test::assert_eq(&3.3.fract(), &m1.fract(kilogram).get(kilogram));
             }
+
+            #[test]
+            fn mixed() {
+                let m = Length::new::<meter>(1_234.5).into_mixed((kilometer, meter));
+
+                Test::assert_eq(&1.0, &m.values.0);
+                Test::assert_approx_eq(&234.5, &m.values.1);
+                assert_eq!("1 km 234.5 m", format!("{:.1}", m));
+                Test::assert_approx_eq(&1_234.5, &m.into_quantity::<U<V>>().get(meter));
+
+                let m = Length::new::<meter>(-2_500.0).into_mixed((kilometer, meter));
+
+                Test::assert_eq(&-2.0, &m.values.0);
+                Test::assert_approx_eq(&-500.0, &m.values.1);
+                assert_eq!("-2 km 500 m", format!("{:.0}", m));
+                Test::assert_approx_eq(&-2_500.0,
+                    &Length::from_mixed((kilometer, meter), m.values).get(meter));
+
+                let m = Length::new::<kilometer>(3.0).into_mixed((kilometer, meter));
+
+                Test::assert_eq(&3.0, &m.values.0);
+                Test::assert_eq(&0.0, &m.values.1);
+                assert_eq!("3 km 0 m", format!("{}", m));
+                assert_eq!("1 km 0 m",
+                    format!("{:.0}", Length::new::<meter>(999.6).into_mixed((kilometer, meter))));
+                assert_eq!("-1 km 0 m",
+                    format!("{:.0}", Length::new::<meter>(-999.6).into_mixed((kilometer, meter))));
+            }
         }
     }
 }
@@ -459,6 +685,18 @@ mod system_macro {
                             &*v))
             }
 
+            #[allow(trivial_casts)]
+            fn into_units(v: A<V>) -> bool {
+                let k = length::Length::<KilometerKilogram, V>::new::<kilometer>((*v).clone());
+                let m = k.into_units::<MeterKilogram>();
+
+                Test::approx_eq(&::tests::change_base::<length::Dimension, MeterKilogram,
+                            KilometerKilogram, V>(&*v),
+                        &m.value)
+                    && Test::approx_eq(&*v, &m.get(kilometer))
+                    && Test::approx_eq(&*v, &m.into_units::<KilometerKilogram>().value)
+            }
+
             #[allow(trivial_casts)]
             fn add(l: A<V>, r: A<V>) -> bool {
                 Test::eq(&(&*l + &*r),
@@ -466,6 +704,24 @@ mod system_macro {
                         + Length::new::<meter>((*r).clone())).get(meter))
             }
 
+            #[allow(trivial_casts)]
+            fn sum(l: A<V>, r: A<V>) -> bool {
+                let q = [Length::new::<meter>((*l).clone()), Length::new::<meter>((*r).clone())];
+
+                Test::eq(&(&*l + &*r), &q.iter().sum::<Length>().get(meter))
+                    && Test::eq(&(&*l + &*r), &q.iter().cloned().sum::<Length>().get(meter))
+            }
+
+            #[allow(trivial_casts)]
+            fn product(l: A<V>, r: A<V>) -> bool {
+                let q = [Length::new::<meter>((*l).clone()), Length::new::<meter>((*r).clone())];
+
+                Test::eq(&(&*l * &*r),
+                        &Length::product(P2::new(), q.iter().cloned()).unwrap().value)
+                    && Length::product(P3::new(), q.iter().cloned()).is_none()
+                    && Length::product(P1::new(), q.iter().cloned()).is_none()
+            }
+
             #[allow(trivial_casts)]
             fn sub(l: A<V>, r: A<V>) -> bool {
                 Test::eq(&(&*l - &*r),
@@ -527,6 +783,49 @@ mod system_macro {
                         &(Length::new::<meter>((*l).clone())
                             % Length::new::<meter>((*r).clone())).get(meter)))
             }
+
+            #[allow(trivial_casts)]
+            fn eq(l: A<V>, r: A<V>) -> bool {
+                (*l == *r)
+                    == (Length::new::<meter>((*l).clone()) == Length::new::<meter>((*r).clone()))
+            }
+
+            #[allow(trivial_casts)]
+            fn ne(l: A<V>, r: A<V>) -> bool {
+                (*l != *r)
+                    == (Length::new::<meter>((*l).clone()) != Length::new::<meter>((*r).clone()))
+            }
+
+            #[allow(trivial_casts)]
+            fn partial_cmp(l: A<V>, r: A<V>) -> bool {
+                (*l).partial_cmp(&*r)
+                    == Length::new::<meter>((*l).clone())
+                        .partial_cmp(&Length::new::<meter>((*r).clone()))
+            }
+
+            #[allow(trivial_casts)]
+            fn lt(l: A<V>, r: A<V>) -> bool {
+                (*l).lt(&*r)
+                    == Length::new::<meter>((*l).clone()).lt(&Length::new::<meter>((*r).clone()))
+            }
+
+            #[allow(trivial_casts)]
+            fn le(l: A<V>, r: A<V>) -> bool {
+                (*l).le(&*r)
+                    == Length::new::<meter>((*l).clone()).le(&Length::new::<meter>((*r).clone()))
+            }
+
+            #[allow(trivial_casts)]
+            fn gt(l: A<V>, r: A<V>) -> bool {
+                (*l).gt(&*r)
+                    == Length::new::<meter>((*l).clone()).gt(&Length::new::<meter>((*r).clone()))
+            }
+
+            #[allow(trivial_casts)]
+            fn ge(l: A<V>, r: A<V>) -> bool {
+                (*l).ge(&*r)
+                    == Length::new::<meter>((*l).clone()).ge(&Length::new::<meter>((*r).clone()))
+            }
         }
     }
 
@@ -657,6 +956,174 @@ mod system_macro {
         }
     }
 
+    #[cfg(feature = "approx")]
+    mod approx {
+        storage_types! {
+            types: Float;
+
+            use tests::*;
+
+            type KilometerKilogram = Units<V, length = kilometer, mass = kilogram>;
+
+            Q!(tests, V);
+
+            #[test]
+            fn abs_diff_eq() {
+                let m = Length::new::<meter>(1_000.05);
+                let k = length::Length::<KilometerKilogram, V>::new::<kilometer>(1.0);
+
+                assert_abs_diff_eq!(m, k, epsilon = Length::new::<meter>(0.1));
+                assert_abs_diff_ne!(m, k, epsilon = Length::new::<meter>(0.01));
+                assert_abs_diff_eq!(m, m);
+            }
+
+            #[test]
+            fn relative_eq() {
+                let m = Length::new::<meter>(1_000.05);
+                let k = length::Length::<KilometerKilogram, V>::new::<kilometer>(1.0);
+                let r = Length::new::<meter>(1.0E-4);
+
+                assert_relative_eq!(m, k, epsilon = Length::new::<meter>(0.0), max_relative = r);
+                assert_relative_ne!(m, k, epsilon = Length::new::<meter>(0.01),
+                    max_relative = Length::new::<meter>(1.0E-6));
+                assert_relative_eq!(k, k);
+            }
+
+            #[test]
+            fn ulps_eq() {
+                let m = Length::new::<meter>(1_000.0);
+                let k = length::Length::<KilometerKilogram, V>::new::<kilometer>(1.0);
+
+                assert_ulps_eq!(m, k);
+                assert_ulps_eq!(m, k, epsilon = Length::new::<meter>(0.0), max_ulps = 4);
+                assert_ulps_ne!(m, Length::new::<meter>(1_000.05), max_ulps = 4);
+            }
+        }
+    }
+
+    #[cfg(feature = "testing")]
+    mod testing {
+        storage_types! {
+            types: Float, PrimInt;
+
+            use tests::*;
+            use testing::{InRange, Range};
+
+            Q!(tests, V);
+
+            #[derive(Clone, Debug)]
+            struct Positive;
+
+            impl Range for Positive {
+                fn min() -> f64 { 1.0 }
+                fn max() -> f64 { 100.0 }
+            }
+
+            quickcheck! {
+                #[allow(trivial_casts)]
+                fn arbitrary(l: Length) -> bool {
+                    Test::eq(&l.value, &l.get(meter))
+                }
+
+                #[allow(trivial_casts)]
+                fn in_range(l: InRange<Length, Positive>) -> bool {
+                    *l >= Length::new::<meter>(V::one())
+                        && l.into_inner() < Length::new::<meter>(V::from_f64(100.0).unwrap())
+                }
+            }
+
+            #[derive(Clone, Debug)]
+            struct Empty;
+
+            impl Range for Empty {
+                fn min() -> f64 { 1.0 }
+                fn max() -> f64 { 1.0 }
+            }
+
+            #[test]
+            #[should_panic(expected = "`Range::min` must be less than `Range::max`")]
+            fn in_range_empty() {
+                fn prop(_: InRange<Length, Empty>) -> bool { true }
+
+                ::quickcheck::quickcheck(prop as fn(InRange<Length, Empty>) -> bool);
+            }
+        }
+
+        mod unsigned {
+            storage_types! {
+                types: u8, u16, u32, u64, usize;
+
+                use tests::*;
+                use testing::{InRange, Range};
+
+                Q!(tests, V);
+
+                #[derive(Clone, Debug)]
+                struct Negative;
+
+                impl Range for Negative {
+                    fn min() -> f64 { -2.0 }
+                    fn max() -> f64 { -1.0 }
+                }
+
+                #[test]
+                #[should_panic(expected = "can be represented by the storage type")]
+                fn in_range_unrepresentable() {
+                    fn prop(_: InRange<Length, Negative>) -> bool { true }
+
+                    ::quickcheck::quickcheck(prop as fn(InRange<Length, Negative>) -> bool);
+                }
+            }
+        }
+
+        mod float {
+            storage_types! {
+                types: Float;
+
+                use tests::*;
+
+                type KilometerKilogram = Units<V, length = kilometer, mass = kilogram>;
+
+                Q!(tests, V);
+
+                #[test]
+                fn assert_quantity_approx_eq() {
+                    let m = Length::new::<meter>(1_000.0);
+                    let k = length::Length::<KilometerKilogram, V>::new::<kilometer>(1.0);
+
+                    assert_quantity_approx_eq!(m, k, kilometer);
+                    assert_quantity_approx_eq!(m, k, meter, "{} == {}", 1, 1);
+                }
+
+                #[test]
+                #[should_panic(expected = "left: `1 km`,\n right: `2 km`: message")]
+                fn assert_quantity_approx_eq_fail() {
+                    assert_quantity_approx_eq!(Length::new::<kilometer>(1.0),
+                        Length::new::<kilometer>(2.0), kilometer, "message");
+                }
+            }
+        }
+    }
+
+    mod ord {
+        storage_types! {
+            types: PrimInt, BigInt, BigUint, Ratio;
+
+            use tests::*;
+
+            Q!(tests, V);
+
+            quickcheck! {
+                #[allow(trivial_casts)]
+                fn cmp(l: A<V>, r: A<V>) -> bool {
+                    (*l).cmp(&*r)
+                        == Length::new::<meter>((*l).clone())
+                            .cmp(&Length::new::<meter>((*r).clone()))
+                }
+            }
+        }
+    }
+
     mod signed {
         storage_types! {
             types: Signed;
@@ -758,6 +1225,307 @@ mod system_macro {
             }
         }
     }
+
+    mod prim_int {
+        storage_types! {
+            types: PrimInt;
+
+            use tests::*;
+
+            Q!(tests, V);
+
+            quickcheck! {
+                #[allow(trivial_casts)]
+                fn checked_add(l: A<V>, r: A<V>) -> bool {
+                    (*l).checked_add(*r)
+                        == Length::new::<meter>(*l).checked_add(Length::new::<meter>(*r))
+                            .map(|l| l.get(meter))
+                }
+
+                #[allow(trivial_casts)]
+                fn checked_sub(l: A<V>, r: A<V>) -> bool {
+                    (*l).checked_sub(*r)
+                        == Length::new::<meter>(*l).checked_sub(Length::new::<meter>(*r))
+                            .map(|l| l.get(meter))
+                }
+
+                #[allow(trivial_casts)]
+                fn checked_mul(l: A<V>, r: A<V>) -> bool {
+                    (*l).checked_mul(*r)
+                        == Length::new::<meter>(*l).checked_mul(Length::new::<meter>(*r))
+                            .map(|a| a.value)
+                }
+
+                #[allow(trivial_casts)]
+                fn checked_div(l: A<V>, r: A<V>) -> bool {
+                    (*l).checked_div(*r)
+                        == Length::new::<meter>(*l).checked_div(Length::new::<meter>(*r))
+                            .map(|r| r.value)
+                }
+
+                #[allow(trivial_casts)]
+                fn saturating_add(l: A<V>, r: A<V>) -> bool {
+                    (*l).saturating_add(*r)
+                        == Length::new::<meter>(*l).saturating_add(Length::new::<meter>(*r))
+                            .get(meter)
+                }
+
+                #[allow(trivial_casts)]
+                fn saturating_sub(l: A<V>, r: A<V>) -> bool {
+                    (*l).saturating_sub(*r)
+                        == Length::new::<meter>(*l).saturating_sub(Length::new::<meter>(*r))
+                            .get(meter)
+                }
+
+                #[allow(trivial_casts)]
+                fn saturating_mul(l: A<V>, r: A<V>) -> bool {
+                    (*l).saturating_mul(*r)
+                        == Length::new::<meter>(*l).saturating_mul(Length::new::<meter>(*r))
+                            .value
+                }
+
+                #[allow(trivial_casts)]
+                fn saturating_div(l: A<V>, r: A<V>) -> TestResult {
+                    if *r == V::zero() {
+                        return TestResult::discard();
+                    }
+
+                    TestResult::from_bool(
+                        (*l).checked_div(*r).unwrap_or(V::max_value())
+                            == Length::new::<meter>(*l).saturating_div(Length::new::<meter>(*r))
+                                .value)
+                }
+
+                #[allow(trivial_casts)]
+                fn wrapping_add(l: A<V>, r: A<V>) -> bool {
+                    (*l).wrapping_add(*r)
+                        == Length::new::<meter>(*l).wrapping_add(Length::new::<meter>(*r))
+                            .get(meter)
+                }
+
+                #[allow(trivial_casts)]
+                fn wrapping_sub(l: A<V>, r: A<V>) -> bool {
+                    (*l).wrapping_sub(*r)
+                        == Length::new::<meter>(*l).wrapping_sub(Length::new::<meter>(*r))
+                            .get(meter)
+                }
+
+                #[allow(trivial_casts)]
+                fn wrapping_mul(l: A<V>, r: A<V>) -> bool {
+                    (*l).wrapping_mul(*r)
+                        == Length::new::<meter>(*l).wrapping_mul(Length::new::<meter>(*r))
+                            .value
+                }
+
+                #[allow(trivial_casts)]
+                fn wrapping_div(l: A<V>, r: A<V>) -> TestResult {
+                    if *r == V::zero() {
+                        return TestResult::discard();
+                    }
+
+                    TestResult::from_bool(
+                        (*l).wrapping_div(*r)
+                            == Length::new::<meter>(*l).wrapping_div(Length::new::<meter>(*r))
+                                .value)
+                }
+            }
+
+            #[test]
+            fn overflow() {
+                let max = Length::new::<meter>(V::max_value());
+                let min = Length::new::<meter>(V::min_value());
+                let one = Length::new::<meter>(V::one());
+
+                assert_eq!(None, max.checked_add(one));
+                assert_eq!(None, min.checked_sub(one));
+                assert_eq!(None, max.checked_mul(max));
+                assert_eq!(None, max.checked_div(Length::new::<meter>(V::zero())));
+                assert_eq!(V::max_value(), max.saturating_add(one).get(meter));
+                assert_eq!(V::min_value(), min.saturating_sub(one).get(meter));
+                assert_eq!(V::max_value(), max.saturating_mul(max).value);
+                assert_eq!(V::min_value(), max.wrapping_add(one).get(meter));
+                assert_eq!(V::max_value(), min.wrapping_sub(one).get(meter));
+            }
+        }
+    }
+
+    mod cast {
+        use tests::*;
+
+        storage_types! {
+            types: PrimInt, Float;
+
+            use tests::*;
+
+            Q!(tests, V);
+
+            quickcheck! {
+                #[allow(trivial_casts)]
+                fn try_cast(v: A<V>) -> bool {
+                    Test::eq(&*v, &Length::new::<meter>(*v).try_cast::<V>().unwrap().get(meter))
+                }
+            }
+        }
+
+        #[cfg(all(feature = "f32", feature = "f64"))]
+        #[test]
+        fn from_float() {
+            let l: length::Length<U<f64>, f64> =
+                length::Length::<U<f32>, f32>::new::<meter>(1.5).into();
+
+            assert_eq!(1.5, l.get(meter));
+        }
+
+        #[cfg(all(feature = "u8", feature = "i32"))]
+        #[test]
+        fn prim_int() {
+            let l = length::Length::<U<u8>, u8>::new::<meter>(255);
+            let i: length::Length<U<i32>, i32> = l.into();
+
+            assert_eq!(255, i.get(meter));
+            assert_eq!(Some(l), i.try_cast::<u8>());
+            assert_eq!(None, (i + i).try_cast::<u8>());
+            assert_eq!(None, (-i).try_cast::<u8>());
+        }
+
+        #[cfg(all(feature = "f64", feature = "i32"))]
+        #[test]
+        fn float_to_prim_int() {
+            let l = |v| length::Length::<U<f64>, f64>::new::<meter>(v);
+
+            assert_eq!(Some(-1), l(-1.5).try_cast::<i32>().map(|l| l.get(meter)));
+            assert_eq!(None, l(1.0E10).try_cast::<i32>());
+            assert_eq!(None, l(::lib::f64::NAN).try_cast::<i32>());
+        }
+
+        #[cfg(all(feature = "rational32", feature = "f64", feature = "i32", feature = "u8"))]
+        #[test]
+        fn ratio() {
+            use num::rational::Rational32;
+
+            let r = |n, d| length::Length::<U<Rational32>, Rational32>::new::<meter>(
+                Rational32::new(n, d));
+            let i: length::Length<U<Rational32>, Rational32> =
+                length::Length::<U<i32>, i32>::new::<meter>(3).into();
+
+            assert_eq!(Some(1.0 / 3.0), r(1, 3).try_cast::<f64>().map(|l| l.get(meter)));
+            assert_eq!(Some(-1), r(-3, 2).try_cast::<i32>().map(|l| l.get(meter)));
+            assert_eq!(Some(0), r(-1, 2).try_cast::<u8>().map(|l| l.get(meter)));
+            assert_eq!(None, r(-3, 2).try_cast::<u8>());
+            assert_eq!(Rational32::from_integer(3), i.get(meter));
+        }
+
+        #[cfg(all(feature = "bigrational", feature = "f32"))]
+        #[test]
+        fn big_ratio() {
+            use num::{BigInt, BigRational, Num};
+
+            // The numerator is out of range for `f32` even though the value isn't.
+            let n = BigInt::from_str_radix("1000000000000000000000000000000000000000", 10).unwrap();
+            let l = length::Length::<U<BigRational>, BigRational>::new::<meter>(
+                BigRational::new(n, 3_000_000_000_u64.into()));
+
+            Test::assert_approx_eq(&3.333_333_3_E29,
+                &l.try_cast::<f32>().map(|l| l.get(meter)).unwrap());
+        }
+
+        #[cfg(all(feature = "bigint", feature = "biguint", feature = "u64"))]
+        #[test]
+        fn big_int() {
+            use num::{BigInt, BigUint};
+
+            let l = |v| length::Length::<U<BigInt>, BigInt>::new::<meter>(v);
+            let max: length::Length<U<BigUint>, BigUint> =
+                length::Length::<U<u64>, u64>::new::<meter>(u64::max_value()).into();
+            let max: length::Length<U<BigInt>, BigInt> = max.into();
+
+            assert_eq!(Some(u64::max_value()), max.clone().try_cast::<u64>().map(|l| l.get(meter)));
+            assert_eq!(None, l(max.get(meter) + BigInt::one()).try_cast::<u64>());
+        }
+    }
+
+    mod dyn_quantity {
+        storage_types! {
+            types: Float;
+
+            use tests::*;
+            use DimensionError;
+
+            mod f { Q!(tests, super::V); }
+            mod k { Q!(tests, super::V, (kilometer, kilogram)); }
+
+            #[test]
+            fn from() {
+                let l: DynQuantity<V> = k::Length::new::<kilometer>(2.0).into();
+                let m: DynQuantity<V> = f::Mass::new::<kilogram>(3.0).into();
+
+                assert_eq!([1, 0], l.dimension);
+                Test::assert_eq(&2_000.0, &l.value);
+                assert_eq!([0, 1], m.dimension);
+                Test::assert_eq(&3.0, &m.value);
+            }
+
+            #[test]
+            fn try_into_quantity() {
+                let l: DynQuantity<V> = f::Length::new::<meter>(2_000.0).into();
+                let k: k::Length = l.try_into_quantity().unwrap();
+
+                Test::assert_eq(&2.0, &k.value);
+                assert_eq!(Err(DimensionError), l.try_into_quantity::<mass::Dimension, U<V>>());
+            }
+
+            #[cfg(feature = "try_from")]
+            #[test]
+            fn try_from() {
+                use lib::convert::TryFrom;
+
+                let l: DynQuantity<V> = f::Length::new::<meter>(2_000.0).into();
+
+                Test::assert_eq(&2.0, &k::Length::try_from(l).unwrap().value);
+                assert_eq!(Err(DimensionError), k::Mass::try_from(l));
+            }
+
+            #[test]
+            fn ops() {
+                let l: DynQuantity<V> = f::Length::new::<meter>(2.0).into();
+                let m: DynQuantity<V> = f::Mass::new::<kilogram>(3.0).into();
+
+                assert_eq!(Ok(DynQuantity::from(f::Length::new::<meter>(4.0))), l + l);
+                assert_eq!(Ok(DynQuantity::from(f::Length::new::<meter>(0.0))), l - l);
+                assert_eq!(Err(DimensionError), l + m);
+                assert_eq!(Err(DimensionError), l - m);
+                assert_eq!(Ok([1, 1]), (l * m).map(|q| q.dimension));
+                Test::assert_eq(&6.0, &(l * m).unwrap().value);
+                assert_eq!(Ok([1, -1]), (l / m).map(|q| q.dimension));
+                assert_eq!(DynQuantity::from(f::Length::new::<meter>(4.0)), l * 2.0);
+                assert_eq!(DynQuantity::from(f::Length::new::<meter>(1.0)), l / 2.0);
+                assert_eq!(DynQuantity::from(f::Length::new::<meter>(-2.0)), -l);
+                assert_eq!(Ok(m), (l * m).and_then(|q| q / l)
+                    .and_then(|q| q.try_into_quantity::<mass::Dimension, U<V>>())
+                    .map(DynQuantity::from));
+            }
+
+            #[test]
+            fn ops_overflow() {
+                let l: DynQuantity<V> = f::Length::new::<meter>(2.0).into();
+                let max = DynQuantity { dimension: [i32::max_value(), 0], value: V::one() };
+                let min = DynQuantity { dimension: [i32::min_value(), 0], value: V::one() };
+
+                assert_eq!(Err(DimensionError), max * l);
+                assert_eq!(Err(DimensionError), min / l);
+                assert_eq!(Ok([i32::max_value() - 1, 0]), (max / l).map(|q| q.dimension));
+            }
+
+            #[test]
+            fn partial_cmp() {
+                let l: DynQuantity<V> = f::Length::new::<meter>(2.0).into();
+                let m: DynQuantity<V> = f::Mass::new::<kilogram>(3.0).into();
+
+                assert!(l < l * 2.0);
+                assert_eq!(None, l.partial_cmp(&m));
+            }
+        }
+    }
 }
 
 mod quantities_macro {
@@ -848,6 +1616,167 @@ mod quantities_macro {
                                 % f::Length::new::<meter>((*r).clone())).get(meter)))
                 }
             }
+
+            #[test]
+            fn eq() {
+                let l1 = k::Length::new::<kilometer>(V::one());
+                let l2 = f::Length::new::<meter>(V::from_f64(1000.0).unwrap());
+                let l3 = f::Length::new::<meter>(V::one());
+
+                assert!(l1 == l2);
+                assert!(l2 == l1);
+                assert!(l1 != l3);
+                assert!(l3 != l1);
+            }
+
+            #[test]
+            fn partial_cmp() {
+                let l1 = k::Length::new::<kilometer>(V::one());
+                let l2 = f::Length::new::<meter>(V::from_f64(1000.0).unwrap());
+                let l3 = f::Length::new::<meter>(V::one());
+
+                assert_eq!(Some(::lib::cmp::Ordering::Equal), l1.partial_cmp(&l2));
+                assert_eq!(Some(::lib::cmp::Ordering::Greater), l1.partial_cmp(&l3));
+                assert_eq!(Some(::lib::cmp::Ordering::Less), l3.partial_cmp(&l1));
+                assert!(l3 < l1);
+                assert!(l3 <= l1);
+                assert!(l1 <= l2);
+                assert!(l1 > l3);
+                assert!(l1 >= l3);
+                assert!(l2 >= l1);
+            }
+        }
+    }
+
+    mod prim_int {
+        storage_types! {
+            // Kilometer conversion factor overflows `i8` and `u8`.
+            types: i16, i32, i64, isize, u16, u32, u64, usize;
+
+            use tests::*;
+
+            mod f { Q!(tests, super::V); }
+            mod k { Q!(tests, super::V, (kilometer, kilogram)); }
+
+            #[test]
+            fn change_base_overflow() {
+                let m = f::Length::new::<meter>(V::one());
+                // Struct literal as `new` would overflow converting the value to base units.
+                let km = k::Length { dimension: PhantomData, units: PhantomData,
+                    value: V::max_value(), };
+                let factor = V::from_u16(1000).unwrap();
+
+                assert_eq!(None, m.checked_add(km));
+                assert_eq!(None, m.checked_sub(km));
+                assert_eq!(None, m.checked_mul(km));
+                assert_eq!(V::max_value(), m.saturating_add(km).get(meter));
+                assert_eq!(V::max_value(), m.saturating_mul(km).value);
+                assert_eq!(V::one().wrapping_add(V::max_value().wrapping_mul(factor)),
+                    m.wrapping_add(km).get(meter));
+                assert_eq!(Some(V::one() + factor),
+                    m.checked_add(k::Length::new::<kilometer>(V::one())).map(|l| l.get(meter)));
+            }
+
+            #[test]
+            fn try_new() {
+                let v = |v| V::from_u16(v).unwrap();
+
+                assert_eq!(Ok(V::one()), k::Length::try_new::<meter>(v(1000)).map(|l| l.value));
+                assert_eq!(Ok(v(1000)), f::Length::try_new::<kilometer>(V::one()).map(|l| l.value));
+                assert_eq!(Err(ConversionError::Inexact), k::Length::try_new::<meter>(v(1500)));
+                assert_eq!(Err(ConversionError::Overflow),
+                    f::Length::try_new::<kilometer>(V::max_value()));
+            }
+
+            #[test]
+            fn try_get() {
+                let v = |v| V::from_u16(v).unwrap();
+                let km = k::Length { dimension: PhantomData, units: PhantomData,
+                    value: V::max_value(), };
+
+                assert_eq!(Ok(v(2)), f::Length::new::<meter>(v(2000)).try_get(kilometer));
+                assert_eq!(Ok(v(2000)), k::Length::new::<kilometer>(v(2)).try_get(meter));
+                assert_eq!(Err(ConversionError::Inexact),
+                    f::Length::new::<meter>(v(2001)).try_get(kilometer));
+                assert_eq!(Err(ConversionError::Overflow), km.try_get(meter));
+            }
+
+            #[test]
+            fn rounded() {
+                let v = |v| V::from_u16(v).unwrap();
+                let test = |m, mode| {
+                    let r = k::Length::new_rounded::<meter>(v(m), mode).value;
+
+                    assert_eq!(r, f::Length::new::<meter>(v(m)).get_rounded(kilometer, mode));
+
+                    r
+                };
+
+                assert_eq!(v(1), test(1500, RoundingMode::Truncate));
+                assert_eq!(v(1), test(1500, RoundingMode::Floor));
+                assert_eq!(v(2), test(1500, RoundingMode::Ceil));
+                assert_eq!(v(2), test(1500, RoundingMode::HalfEven));
+                assert_eq!(v(2), test(2500, RoundingMode::HalfEven));
+                assert_eq!(v(2), test(2499, RoundingMode::HalfEven));
+                assert_eq!(v(3), test(2501, RoundingMode::HalfEven));
+                assert_eq!(v(2), test(2000, RoundingMode::Ceil));
+            }
+        }
+
+        storage_types! {
+            types: i8, u8;
+
+            use tests::*;
+
+            mod f { Q!(tests, super::V); }
+            mod k { Q!(tests, super::V, (kilometer, kilogram)); }
+
+            #[test]
+            fn change_base_factor_out_of_range() {
+                let m = f::Length::new::<meter>(V::one());
+                // Struct literal as `new` would panic on the kilometer conversion factor.
+                let km = k::Length { dimension: PhantomData, units: PhantomData,
+                    value: V::one(), };
+
+                assert_eq!(None, m.checked_add(km));
+                assert_eq!(None, m.checked_sub(km));
+                assert_eq!(None, m.checked_mul(km));
+                assert_eq!(V::max_value(), m.saturating_add(km).get(meter));
+                assert_eq!(V::max_value(), m.saturating_mul(km).value);
+                assert_eq!(V::one(), m.saturating_add(k::Length { dimension: PhantomData,
+                    units: PhantomData, value: V::zero(), }).get(meter));
+            }
+        }
+    }
+
+    mod signed {
+        storage_types! {
+            types: i16, i32, i64, isize;
+
+            use tests::*;
+
+            mod f { Q!(tests, super::V); }
+            mod k { Q!(tests, super::V, (kilometer, kilogram)); }
+
+            #[test]
+            fn rounded() {
+                let v = |v| V::from_i16(v).unwrap();
+                let test = |m, mode| {
+                    let r = k::Length::new_rounded::<meter>(v(m), mode).value;
+
+                    assert_eq!(r, f::Length::new::<meter>(v(m)).get_rounded(kilometer, mode));
+
+                    r
+                };
+
+                assert_eq!(v(-1), test(-1500, RoundingMode::Truncate));
+                assert_eq!(v(-2), test(-1500, RoundingMode::Floor));
+                assert_eq!(v(-1), test(-1500, RoundingMode::Ceil));
+                assert_eq!(v(-2), test(-1500, RoundingMode::HalfEven));
+                assert_eq!(v(-2), test(-2500, RoundingMode::HalfEven));
+                assert_eq!(v(-3), test(-2501, RoundingMode::HalfEven));
+                assert_eq!(Err(ConversionError::Inexact), k::Length::try_new::<meter>(v(-1)));
+            }
         }
     }
 
@@ -1013,7 +1942,8 @@ mod static_checks {
 
         use tests::*;
 
-        assert_impl!(q; Quantity<Q<Z0, Z0>, U<V>, V>, Clone, Copy, Send, Sync);
+        assert_impl!(q; Quantity<Q<Z0, Z0>, U<V>, V>, Clone, Copy, PartialEq, PartialOrd, Send,
+            Sync);
     }
 
     storage_types! {
@@ -1022,9 +1952,11 @@ mod static_checks {
         use tests::*;
 
         #[cfg(feature = "std")]
-        assert_impl!(q; Quantity<Q<Z0, Z0>, U<V>, V>, Clone, Copy, Send, Sync, ::lib::hash::Hash);
+        assert_impl!(q; Quantity<Q<Z0, Z0>, U<V>, V>, Clone, Copy, Eq, Ord, PartialEq, PartialOrd,
+            Send, Sync, ::lib::hash::Hash);
         #[cfg(not(feature = "std"))]
-        assert_impl!(q; Quantity<Q<Z0, Z0>, U<V>, V>, Clone, Copy, Send, Sync, ::lib::hash::Hash);
+        assert_impl!(q; Quantity<Q<Z0, Z0>, U<V>, V>, Clone, Copy, Eq, Ord, PartialEq, PartialOrd,
+            Send, Sync, ::lib::hash::Hash);
     }
 
     storage_types! {
@@ -1033,8 +1965,10 @@ mod static_checks {
         use tests::*;
 
         #[cfg(feature = "std")]
-        assert_impl!(q; Quantity<Q<Z0, Z0>, U<V>, V>, Clone, Send, Sync, ::lib::hash::Hash);
+        assert_impl!(q; Quantity<Q<Z0, Z0>, U<V>, V>, Clone, Eq, Ord, PartialEq, PartialOrd, Send,
+            Sync, ::lib::hash::Hash);
         #[cfg(not(feature = "std"))]
-        assert_impl!(q; Quantity<Q<Z0, Z0>, U<V>, V>, Clone, Send, Sync, ::lib::hash::Hash);
+        assert_impl!(q; Quantity<Q<Z0, Z0>, U<V>, V>, Clone, Eq, Ord, PartialEq, PartialOrd, Send,
+            Sync, ::lib::hash::Hash);
     }
 }